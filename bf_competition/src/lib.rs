@@ -4,8 +4,9 @@
 // Title: BF Art Competition Contract
 // Description: A Soroban smart contract for managing art competitions, allowing artists to submit artworks, vote on them, and determine winners.
 // This contract includes features for artist registration, competition management, voting, and artist metadata storage.
-// Version: 1.0.1
+// Version: 2.0.0
 #![no_std]
+#![allow(clippy::too_many_arguments)]
 
 use core::convert::TryInto;
 use soroban_sdk::{
@@ -26,11 +27,106 @@ pub struct ArtworkMetadata {
 pub struct VotingEligibility {
     pub can_vote: bool,
     pub has_voted: bool,
-    pub current_balance: u64,
-    pub min_required: u64,
+    pub current_balance: i128,
+    pub min_required: i128,
     pub voting_active: bool,
 }
 
+#[derive(Clone, Copy, PartialEq, Eq)]
+#[contracttype]
+pub enum FeeMode {
+    OnFund,
+    OnPayout,
+}
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+#[contracttype]
+pub enum VoterRewardMode {
+    WinnerBackers,
+    AllVoters,
+}
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+#[contracttype]
+pub enum CompetitionState {
+    Draft,
+    Scheduled,
+    Submissions,
+    Voting,
+    Tallying,
+    Finalized,
+    Paid,
+    Cancelled,
+    Archived,
+}
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+#[contracttype]
+pub enum TieBreak {
+    SplitEvenly,
+    EarliestSubmission,
+    JuryDecides,
+    Random,
+}
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+#[contracttype]
+pub enum ReviewStatus {
+    Pending,
+    Approved,
+    Rejected,
+}
+
+#[derive(Clone)]
+#[contracttype]
+pub struct SubmissionReview {
+    pub status: ReviewStatus,
+    pub reason: Option<u32>,
+    pub reviewed_at: u64,
+}
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+#[contracttype]
+pub enum ReportReason {
+    Plagiarism,
+    Nsfw,
+    RuleViolation,
+}
+
+#[derive(Clone)]
+#[contracttype]
+pub struct ReportPolicy {
+    pub max_per_address: u32,
+    pub deposit: i128,
+    pub threshold: u32,
+}
+
+#[derive(Clone)]
+#[contracttype]
+pub struct Report {
+    pub reporter: Address,
+    pub artist: String,
+    pub reason: ReportReason,
+    pub deposit: i128,
+    pub timestamp: u64,
+    pub resolved: bool,
+}
+
+#[derive(Clone)]
+#[contracttype]
+pub struct EntryFee {
+    pub token: Address,
+    pub amount: i128,
+    pub pot_pct: u32,
+}
+
+#[derive(Clone)]
+#[contracttype]
+pub struct PayoutSchedule {
+    pub cliff: u64,
+    pub duration: u64,
+}
+
 #[derive(Clone)]
 #[contracttype]
 pub struct Competition {
@@ -41,15 +137,39 @@ pub struct Competition {
     pub artist_add_end: u64,
     pub vote_start: u64,
     pub vote_end: u64,
-    pub min_vote_tokens: u64,
+    pub min_vote_tokens: i128,
     pub artists: Vec<(Address, String)>,
     pub votes: Map<String, u64>,
     pub vote_log: Map<Address, String>,
-    pub finalized: bool,
+    pub state: CompetitionState,
     pub winner: Option<String>,
-    pub pot: u64,
+    pub pots: Map<Address, i128>,
     pub artist_metadata: Map<String, Vec<ArtworkMetadata>>,
     pub share_ratio: Vec<u32>,
+    pub claim_deadline: u64,
+    pub fee_bps: u32,
+    pub fee_mode: FeeMode,
+    pub voter_reward_pct: u32,
+    pub voter_reward_mode: VoterRewardMode,
+    pub fees_held: Map<Address, i128>,
+    pub keeper_paid: i128,
+    pub entry_fee: EntryFee,
+    pub payout_schedule: PayoutSchedule,
+    pub tie_break: TieBreak,
+    pub jury_order: Vec<String>,
+    pub ranking: Vec<ArtistRanking>,
+    pub successor: Option<String>,
+    pub paused_at: u64,
+    pub max_artists: u32,
+    pub max_artworks_per_artist: u32,
+    pub artwork_votes: Map<String, Map<String, u64>>,
+    pub withdraw_during_voting: bool,
+    pub require_profile: bool,
+    pub moderated: bool,
+    pub reviews: Map<String, SubmissionReview>,
+    pub report_policy: ReportPolicy,
+    pub hidden: Vec<String>,
+    pub disqualified: Map<String, String>,
 }
 
 #[derive(Clone)]
@@ -76,9 +196,178 @@ pub struct ArtistRanking {
 pub struct VoteHistory {
     pub voter: Address,
     pub artist: String,
+    pub artwork: Option<String>,
+    pub timestamp: u64,
+}
+
+// Competition as stored by version 1.0.1, pot and minimum vote tokens in whole tokens
+#[derive(Clone)]
+#[contracttype]
+pub struct CompetitionV1 {
+    pub id: String,
+    pub id_description: String,
+    pub token: Address,
+    pub artist_add_start: u64,
+    pub artist_add_end: u64,
+    pub vote_start: u64,
+    pub vote_end: u64,
+    pub min_vote_tokens: u64,
+    pub artists: Vec<(Address, String)>,
+    pub votes: Map<String, u64>,
+    pub vote_log: Map<Address, String>,
+    pub finalized: bool,
+    pub winner: Option<String>,
+    pub pot: u64,
+    pub artist_metadata: Map<String, Vec<ArtworkMetadata>>,
+    pub share_ratio: Vec<u32>,
+}
+
+// Vote history entry as stored by version 1.0.1
+#[derive(Clone)]
+#[contracttype]
+pub struct VoteHistoryV1 {
+    pub voter: Address,
+    pub artist: String,
+    pub timestamp: u64,
+}
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+#[contracttype]
+pub enum PayoutKind {
+    Main,
+    Leftover,
+}
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+#[contracttype]
+pub enum PayoutStatus {
+    Pending,
+    Vesting,
+    Paid,
+    Failed,
+    Reclaimed,
+}
+
+#[derive(Clone)]
+#[contracttype]
+pub struct PayoutRecord {
+    pub artist: String,
+    pub address: Address,
+    pub token: Address,
+    pub amount: i128,
+    pub rank: u32,
+    pub kind: PayoutKind,
+    pub status: PayoutStatus,
     pub timestamp: u64,
 }
 
+#[derive(Clone)]
+#[contracttype]
+pub struct PendingPayout {
+    pub artist: String,
+    pub address: Address,
+    pub token: Address,
+    pub amount: i128,
+    pub attempts: u32,
+}
+
+#[derive(Clone)]
+#[contracttype]
+pub struct VoterRewardPool {
+    pub per_voter: Map<Address, i128>,
+    pub eligible_voters: u32,
+    pub claimed: Vec<Address>,
+}
+
+#[derive(Clone)]
+#[contracttype]
+pub struct Sponsor {
+    pub funder: Address,
+    pub token: Address,
+    pub amount: i128,
+    pub first_funded: u64,
+    pub last_funded: u64,
+    pub refunded: bool,
+    pub display_name: Option<String>,
+    pub logo_url: Option<String>,
+    pub link: Option<String>,
+}
+
+#[derive(Clone)]
+#[contracttype]
+pub struct EntryFeePayment {
+    pub artist: String,
+    pub address: Address,
+    pub token: Address,
+    pub amount: i128,
+    pub pot_amount: i128,
+    pub refund_due: bool,
+}
+
+// Artist profiles whose counters a competition incremented, so only those are rolled back
+#[derive(Clone)]
+#[contracttype]
+pub struct StatsCredit {
+    pub participated: Vec<Address>,
+    pub won: Vec<Address>,
+}
+
+#[derive(Clone)]
+#[contracttype]
+pub struct VestingPosition {
+    pub artist: String,
+    pub address: Address,
+    pub token: Address,
+    pub total: i128,
+    pub claimed: i128,
+    pub start: u64,
+}
+
+#[derive(Clone)]
+#[contracttype]
+pub struct VestingStatus {
+    pub artist: String,
+    pub address: Address,
+    pub token: Address,
+    pub total: i128,
+    pub vested: i128,
+    pub claimed: i128,
+    pub remaining: i128,
+}
+
+#[derive(Clone)]
+#[contracttype]
+pub struct WaitlistEntry {
+    pub address: Address,
+    pub artist_name: String,
+    pub artwork: ArtworkMetadata,
+    pub fee_paid: i128,
+    pub joined_at: u64,
+}
+
+#[derive(Clone)]
+#[contracttype]
+pub struct SeriesSchedule {
+    pub submission_duration: u64,
+    pub voting_gap: u64,
+    pub voting_duration: u64,
+    pub cadence: u64,
+}
+
+#[derive(Clone)]
+#[contracttype]
+pub struct CompetitionSeries {
+    pub id: String,
+    pub id_description: String,
+    pub token: Address,
+    pub min_vote_tokens: i128,
+    pub share_ratio: Vec<u32>,
+    pub schedule: SeriesSchedule,
+    pub next_start: u64,
+    pub editions: Vec<String>,
+    pub active: bool,
+}
+
 #[derive(Clone)]
 #[contracttype]
 pub struct Medium {
@@ -107,6 +396,9 @@ pub struct ArtistInfo {
     pub competitions_won: u32,
 }
 
+// Layout of the stored competitions and vote history, see migrate_storage
+const STORAGE_VERSION: u32 = 2;
+
 #[contract]
 pub struct CompetitionContract;
 
@@ -123,6 +415,9 @@ impl CompetitionContract {
         env.storage()
             .instance()
             .set(&Symbol::new(&env, "admin2"), &admin2);
+        env.storage()
+            .instance()
+            .set(&Symbol::new(&env, "storage_ver"), &STORAGE_VERSION);
     }
     /// Update one or both admins of the contract {Only admins can update}
     pub fn update_admins(
@@ -195,9 +490,95 @@ impl CompetitionContract {
         all_info.set(artist_address, artist_info);
         env.storage().instance().set(&artist_info_key, &all_info);
     }
-    
     ///
-    /// Create a new competition {Only admins can create}
+    /// Convert the competitions and vote history stored by version 1.0.1 to the current layout,
+    /// call once right after upgrading {Only admins can migrate}
+    ///
+    /// Pots and minimum vote tokens move from whole tokens to base units. Finalized competitions
+    /// keep their result and are Paid if their pot was paid out, the others follow their schedule.
+    pub fn migrate_storage(env: Env, from: Address) {
+        Self::require_admin(&env, &from, "Only admins can migrate storage");
+
+        let version_key = Symbol::new(&env, "storage_ver");
+        let version: u32 = env.storage().instance().get(&version_key).unwrap_or(1);
+        assert!(version < STORAGE_VERSION, "Storage already migrated");
+
+        let competition_ids: Vec<String> = env
+            .storage()
+            .instance()
+            .get(&Symbol::new(&env, "comp_list"))
+            .unwrap_or(Vec::new(&env));
+        for id in competition_ids.iter() {
+            let old: CompetitionV1 = env.storage().instance().get(&id).unwrap();
+            let decimals: u32 =
+                env.invoke_contract(&old.token, &Symbol::new(&env, "decimals"), vec![&env]);
+            let multiplier = 10i128.checked_pow(decimals).expect("Overflow in decimals");
+
+            let mut comp = Self::new_competition(
+                &env,
+                id.clone(),
+                old.id_description,
+                old.artist_add_start,
+                old.artist_add_end,
+                old.vote_start,
+                old.vote_end,
+                old.token.clone(),
+                old.min_vote_tokens as i128 * multiplier,
+            );
+            // Version 1.0.1 had no platform fee
+            comp.fee_bps = 0;
+            comp.artists = old.artists;
+            comp.votes = old.votes;
+            comp.vote_log = old.vote_log;
+            comp.winner = old.winner;
+            comp.artist_metadata = old.artist_metadata;
+            comp.share_ratio = old.share_ratio;
+            if old.pot > 0 {
+                comp.pots.set(old.token, old.pot as i128 * multiplier);
+            }
+
+            if old.finalized {
+                comp.ranking = Self::rank_artists(&env, &comp, false);
+                comp.state = if old.pot > 0 {
+                    CompetitionState::Finalized
+                } else {
+                    CompetitionState::Paid
+                };
+            } else {
+                comp.state = CompetitionState::Scheduled;
+                Self::sync_state(&env, &mut comp);
+            }
+            env.storage().instance().set(&id, &comp);
+        }
+
+        let vote_history_key = Symbol::new(&env, "vote_hist");
+        let old_history: Map<String, Vec<VoteHistoryV1>> = env
+            .storage()
+            .instance()
+            .get(&vote_history_key)
+            .unwrap_or(Map::new(&env));
+        let mut all_vote_history: Map<String, Vec<VoteHistory>> = Map::new(&env);
+        for (id, entries) in old_history.iter() {
+            let mut history = Vec::new(&env);
+            for entry in entries.iter() {
+                history.push_back(VoteHistory {
+                    voter: entry.voter,
+                    artist: entry.artist,
+                    artwork: None,
+                    timestamp: entry.timestamp,
+                });
+            }
+            all_vote_history.set(id, history);
+        }
+        env.storage()
+            .instance()
+            .set(&vote_history_key, &all_vote_history);
+
+        env.storage().instance().set(&version_key, &STORAGE_VERSION);
+    }
+
+    ///
+    /// Create a new competition, its submission and voting phases run on schedule {Only admins can create}
     pub fn create_competition(
         env: Env,
         from: Address,
@@ -208,27 +589,8 @@ impl CompetitionContract {
         vote_start: u64,
         vote_end: u64,
         token: Address,
-        min_vote_tokens: u64,
+        min_vote_tokens: i128,
     ) {
-        assert!(
-            artist_add_start < artist_add_end,
-            "Invalid submission window"
-        );
-        assert!(
-            vote_start > artist_add_end,
-            "Vote start must be after submission end"
-        );
-        assert!(vote_end > vote_start, "Invalid voting window");
-
-        // Check if competition ID already exists
-        assert!(
-            env.storage()
-                .instance()
-                .get::<String, Competition>(&id)
-                .is_none(),
-            "Competition ID already exists"
-        );
-
         // Check if from is one of the two admins
         let admin1_opt = env
             .storage()
@@ -250,8 +612,9 @@ impl CompetitionContract {
 
         from.require_auth();
 
-        let comp = Competition {
-            id: id.clone(),
+        Self::store_new_competition(
+            &env,
+            id,
             id_description,
             artist_add_start,
             artist_add_end,
@@ -259,109 +622,259 @@ impl CompetitionContract {
             vote_end,
             token,
             min_vote_tokens,
-            artists: Vec::new(&env),
-            votes: Map::new(&env),
-            vote_log: Map::new(&env),
-            finalized: false,
-            winner: None,
-            pot: 0,
-            artist_metadata: Map::new(&env),
-            share_ratio: Vec::from_array(&env, [50u32, 30u32, 20u32]), // Default share ratio
-        };
+            true,
+        );
+    }
 
-        env.storage().instance().set(&id, &comp);
+    /// Create a new competition as a draft, it stays closed until published {Only admins can create}
+    pub fn create_draft_competition(
+        env: Env,
+        from: Address,
+        id: String,
+        id_description: String,
+        artist_add_start: u64,
+        artist_add_end: u64,
+        vote_start: u64,
+        vote_end: u64,
+        token: Address,
+        min_vote_tokens: i128,
+    ) {
+        Self::require_admin(&env, &from, "Only admins can create");
 
-        // Add competition ID to the list
-        let competition_list_key = Symbol::new(&env, "comp_list");
-        let mut competition_ids: Vec<String> = env
-            .storage()
-            .instance()
-            .get(&competition_list_key)
-            .unwrap_or(Vec::new(&env));
-        competition_ids.push_back(id.clone());
-        env.storage()
-            .instance()
-            .set(&competition_list_key, &competition_ids);
+        Self::store_new_competition(
+            &env,
+            id,
+            id_description,
+            artist_add_start,
+            artist_add_end,
+            vote_start,
+            vote_end,
+            token,
+            min_vote_tokens,
+            false,
+        );
     }
 
-    /// Delete a competition and emergency withdraw funds {Only admin can delete}
-    pub fn delete_competition(env: Env, id: String, from: Address) {
-        let comp: Competition = env.storage().instance().get(&id).unwrap();
+    /// Publish a draft competition so its submission and voting phases run on schedule {Only admins can publish}
+    pub fn publish_competition(env: Env, id: String, from: Address) {
+        let mut comp: Competition = env.storage().instance().get(&id).unwrap();
 
-        // Check if from is one of the two admins
-        let admin1: Address = env
-            .storage()
-            .instance()
-            .get(&Symbol::new(&env, "admin1"))
-            .unwrap();
-        let admin2: Address = env
-            .storage()
-            .instance()
-            .get(&Symbol::new(&env, "admin2"))
-            .unwrap();
-        assert!(from == admin1 || from == admin2, "Only admins can delete");
+        Self::require_admin(&env, &from, "Only admins can publish competitions");
 
-        from.require_auth();
+        assert!(
+            comp.state == CompetitionState::Draft,
+            "Competition already published"
+        );
+        Self::set_state(&env, &mut comp, CompetitionState::Scheduled);
+        Self::sync_state(&env, &mut comp);
+        env.storage().instance().set(&id, &comp);
+    }
 
-        // Remove competition from storage before external calls to avoid reentrancy
-        env.storage().instance().remove(&id);
+    /// Update a competition's details and schedule, `None` keeps the current value {Only admins can update}
+    ///
+    /// A phase that has started keeps its start and can only be extended, and a phase that has
+    /// ended can't be changed. The minimum vote tokens can only change before voting starts.
+    pub fn update_competition(
+        env: Env,
+        id: String,
+        from: Address,
+        id_description: Option<String>,
+        artist_add_start: Option<u64>,
+        artist_add_end: Option<u64>,
+        vote_start: Option<u64>,
+        vote_end: Option<u64>,
+        min_vote_tokens: Option<i128>,
+    ) {
+        let mut comp: Competition = env.storage().instance().get(&id).unwrap();
 
-        // Emergency withdraw any remaining funds to the sender (from)
-        if comp.pot > 0 {
-            let decimals: u32 =
-                env.invoke_contract(&comp.token, &Symbol::new(&env, "decimals"), vec![&env]);
-            let multiplier = 10u64.checked_pow(decimals).expect("Overflow in decimals");
-            let amount_stroop = comp
-                .pot
-                .checked_mul(multiplier)
-                .expect("Overflow in pot transfer");
+        Self::require_admin(&env, &from, "Only admins can update competitions");
 
-            env.invoke_contract::<()>(
-                &comp.token,
-                &symbol_short!("transfer"),
-                vec![
-                    &env,
-                    env.current_contract_address().into_val(&env),
-                    from.into_val(&env),
-                    (amount_stroop as i128).into_val(&env),
-                ],
+        assert!(
+            comp.state != CompetitionState::Cancelled,
+            "Competition cancelled"
+        );
+        assert!(Self::is_open(&comp), "Competition already finalized");
+
+        let now = env.ledger().timestamp();
+
+        if let Some(start) = artist_add_start {
+            assert!(now < comp.artist_add_start, "Submissions already started");
+            assert!(start >= now, "Submissions cannot start in the past");
+            comp.artist_add_start = start;
+        }
+        if let Some(end) = artist_add_end {
+            assert!(now <= comp.artist_add_end, "Submissions already ended");
+            assert!(
+                now < comp.artist_add_start || end >= comp.artist_add_end,
+                "Cannot shorten submissions after they started"
+            );
+            comp.artist_add_end = end;
+        }
+        if let Some(start) = vote_start {
+            assert!(now < comp.vote_start, "Voting already started");
+            assert!(start >= now, "Voting cannot start in the past");
+            comp.vote_start = start;
+        }
+        if let Some(end) = vote_end {
+            assert!(now <= comp.vote_end, "Voting already ended");
+            assert!(
+                now < comp.vote_start || end >= comp.vote_end,
+                "Cannot shorten voting after it started"
             );
+            comp.vote_end = end;
+        }
+        if let Some(min_tokens) = min_vote_tokens {
+            assert!(now < comp.vote_start, "Voting already started");
+            assert!(min_tokens >= 0, "Invalid minimum vote tokens");
+            comp.min_vote_tokens = min_tokens;
+        }
+        if let Some(description) = id_description {
+            comp.id_description = description;
         }
 
-        // Remove from competition list
-        let competition_list_key = Symbol::new(&env, "comp_list");
-        let competition_ids: Vec<String> = env
-            .storage()
-            .instance()
-            .get(&competition_list_key)
-            .unwrap_or(Vec::new(&env));
+        // Re-validate the window ordering as in create_competition
+        assert!(
+            comp.artist_add_start < comp.artist_add_end,
+            "Invalid submission window"
+        );
+        assert!(
+            comp.vote_start > comp.artist_add_end,
+            "Vote start must be after submission end"
+        );
+        assert!(comp.vote_end > comp.vote_start, "Invalid voting window");
 
-        let mut new_ids = Vec::new(&env);
-        for comp_id in competition_ids.iter() {
-            if comp_id != id {
-                new_ids.push_back(comp_id);
-            }
+        env.storage().instance().set(&id, &comp);
+    }
+
+    /// Archive a finished, cancelled or abandoned draft competition that no longer holds funds,
+    /// it stays queryable with its history {Only admins can archive}
+    pub fn archive_competition(env: Env, id: String, from: Address) {
+        let mut comp: Competition = env.storage().instance().get(&id).unwrap();
+
+        Self::require_admin(&env, &from, "Only admins can archive");
+
+        // Funds must be refunded, paid out or emergency withdrawn first
+        for (_, amount) in comp.pots.iter().chain(comp.fees_held.iter()) {
+            assert!(amount == 0, "Competition still holds funds");
+        }
+        for payment in Self::get_entry_fees(env.clone(), id.clone()).iter() {
+            assert!(!payment.refund_due, "Competition still holds funds");
+        }
+        for entry in Self::get_waitlist(env.clone(), id.clone()).iter() {
+            assert!(entry.fee_paid == 0, "Competition still holds funds");
+        }
+        for report in Self::get_reports(env.clone(), id.clone()).iter() {
+            assert!(report.deposit == 0, "Competition still holds funds");
         }
-        env.storage()
-            .instance()
-            .set(&competition_list_key, &new_ids);
 
-        // Clean up vote history for this competition
-        let vote_history_key = Symbol::new(&env, "vote_hist");
-        let mut all_vote_history: Map<String, Vec<VoteHistory>> = env
+        Self::set_state(&env, &mut comp, CompetitionState::Archived);
+        env.storage().instance().set(&id, &comp);
+    }
+
+    /// Cancel a competition before it is finalized, or a finalized one without a winner whose pot
+    /// is untouched, and refund entry fees, funders can then claim refunds {Only admin can cancel}
+    pub fn cancel_competition(env: Env, id: String, from: Address) {
+        let mut comp: Competition = env.storage().instance().get(&id).unwrap();
+
+        Self::require_admin(&env, &from, "Only admins can cancel");
+
+        assert!(
+            comp.state != CompetitionState::Cancelled,
+            "Competition already cancelled"
+        );
+        // Paying out releases held fees and a keeper reward spends the pot, refunds would no longer be covered
+        let unsettled = comp.state == CompetitionState::Finalized
+            && comp.winner.is_none()
+            && comp.keeper_paid == 0;
+        assert!(
+            Self::is_open(&comp) || unsettled,
+            "Competition already finalized"
+        );
+
+        Self::set_state(&env, &mut comp, CompetitionState::Cancelled);
+        for (_, artist_name) in comp.artists.clone().iter() {
+            Self::refund_entry_fee(&env, &id, &mut comp, &artist_name);
+        }
+
+        // Refund the waitlist, entries whose refund fails stay until they leave the waitlist
+        let waitlist_key = Symbol::new(&env, "waitlist");
+        let mut all_waitlists: Map<String, Vec<WaitlistEntry>> = env
             .storage()
             .instance()
-            .get(&vote_history_key)
+            .get(&waitlist_key)
             .unwrap_or(Map::new(&env));
+        let mut remaining = Vec::new(&env);
+        for entry in all_waitlists.get(id.clone()).unwrap_or(Vec::new(&env)).iter() {
+            if entry.fee_paid > 0
+                && !Self::try_pay_out(&env, &comp.entry_fee.token, &entry.address, entry.fee_paid)
+            {
+                remaining.push_back(entry);
+            }
+        }
+        all_waitlists.set(id.clone(), remaining);
+        env.storage().instance().set(&waitlist_key, &all_waitlists);
 
-        all_vote_history.remove(id);
-        env.storage()
-            .instance()
-            .set(&vote_history_key, &all_vote_history);
+        // Open reports are moot, their deposits go back to the reporters
+        Self::close_reports(&env, &id, &comp, None, true);
+        env.storage().instance().set(&id, &comp);
+
+        env.events()
+            .publish((Symbol::new(&env, "cancelled"), id), from);
+    }
+
+    /// Withdraw funds nobody has a claim on from a competition's pot of a token to an address,
+    /// prizes, vesting positions, voter rewards and refunds still owed stay put {Only admins can withdraw}
+    pub fn emergency_withdraw(
+        env: Env,
+        id: String,
+        from: Address,
+        token: Address,
+        to: Address,
+        amount: i128,
+    ) {
+        let mut comp: Competition = env.storage().instance().get(&id).unwrap();
+
+        Self::require_admin(&env, &from, "Only admins can emergency withdraw");
+
+        let pot = comp.pots.get(token.clone()).unwrap_or(0);
+        assert!(amount > 0, "Amount must be positive");
+        assert!(amount <= pot, "Amount exceeds pot");
+        if comp.state == CompetitionState::Cancelled {
+            assert!(
+                Self::get_sponsors(env.clone(), id.clone())
+                    .iter()
+                    .all(|sponsor| sponsor.refunded),
+                "Funders still awaiting refunds"
+            );
+        }
+        assert!(
+            amount <= pot - Self::owed_from_pot(&env, &id, &comp, &token),
+            "Amount is owed to claimants"
+        );
+
+        // Update pot before external call
+        comp.pots.set(token.clone(), pot - amount);
+        env.storage().instance().set(&id, &comp);
+
+        env.invoke_contract::<()>(
+            &token,
+            &symbol_short!("transfer"),
+            vec![
+                &env,
+                env.current_contract_address().into_val(&env),
+                to.into_val(&env),
+                amount.into_val(&env),
+            ],
+        );
+
+        env.events().publish(
+            (Symbol::new(&env, "emergency_withdraw"), id),
+            (from, token, to, amount),
+        );
     }
 
     ///
-    /// Remove an artist from a competition {Only admin can remove}
+    /// Remove an artist from a competition before it is finalized {Only admin can remove}
     pub fn remove_artist(env: Env, id: String, from: Address, artist_name: String) {
         let mut comp: Competition = env.storage().instance().get(&id).unwrap();
 
@@ -383,72 +896,43 @@ impl CompetitionContract {
 
         from.require_auth();
 
-        // Find and remove the artist from the artists list
-        let mut new_artists = Vec::new(&env);
-        let mut artist_found = false;
-        let mut artist_address_to_remove: Option<Address> = None;
-        for (addr, name) in comp.artists.iter() {
-            if name != artist_name {
-                new_artists.push_back((addr, name));
-            } else {
-                artist_found = true;
-                artist_address_to_remove = Some(addr);
-            }
-        }
-
-        assert!(artist_found, "Artist not found in competition");
-        comp.artists = new_artists;
-
-        // Remove artist metadata
-        comp.artist_metadata.remove(artist_name.clone());
-
-        // Remove any votes for this artist
-        comp.votes.remove(artist_name.clone());
-
-        // Remove any vote logs that voted for this artist and update vote history
-        let vote_history_key = Symbol::new(&env, "vote_hist");
-        let mut all_vote_history: Map<String, Vec<VoteHistory>> = env
-            .storage()
-            .instance()
-            .get(&vote_history_key)
-            .unwrap_or(Map::new(&env));
+        // Finalized results are corrected with disqualify, which keeps the ranking and payouts consistent
+        assert!(Self::is_open(&comp), "Competition already finalized");
 
-        // Filter out votes for the removed artist from vote history
-        let comp_history = all_vote_history.get(id.clone()).unwrap_or(Vec::new(&env));
-        let mut new_history = Vec::new(&env);
-        let mut voters_to_clear = Vec::new(&env);
+        // Entry fees are only refunded if the artist is removed before voting starts
+        let refund = env.ledger().timestamp() < comp.vote_start;
+        // The artist keeps their profile, its counters are rolled back
+        Self::drop_artist(&env, &id, &mut comp, &artist_name, refund);
+        env.storage().instance().set(&id, &comp);
+    }
 
-        for vote_record in comp_history.iter() {
-            if vote_record.artist != artist_name {
-                new_history.push_back(vote_record);
-            } else {
-                voters_to_clear.push_back(vote_record.voter.clone());
-            }
-        }
+    /// Disqualify an artist after voting closed and before prizes are allocated, keeping their submission
+    /// and votes on record. The remaining artists move up and the winner is recounted {Only admins can disqualify}
+    pub fn disqualify(env: Env, id: String, from: Address, artist_name: String, reason: String) {
+        let mut comp: Competition = env.storage().instance().get(&id).unwrap();
 
-        // Clear vote log entries for voters who voted for the removed artist
-        for voter in voters_to_clear.iter() {
-            comp.vote_log.remove(voter.clone());
-        }
+        Self::require_admin(&env, &from, "Only admins can disqualify");
 
-        all_vote_history.set(id.clone(), new_history);
-        env.storage()
-            .instance()
-            .set(&vote_history_key, &all_vote_history);
+        assert!(env.ledger().timestamp() > comp.vote_end, "Voting still active");
+        Self::sync_state(&env, &mut comp);
+        assert!(
+            comp.state == CompetitionState::Tallying || comp.state == CompetitionState::Finalized,
+            "Prizes already allocated"
+        );
+        assert!(
+            comp.artists.iter().any(|(_, name)| name == artist_name),
+            "Artist not found in competition"
+        );
+        assert!(
+            !comp.disqualified.contains_key(artist_name.clone()),
+            "Artist already disqualified"
+        );
 
+        Self::exclude_from_ranking(&env, &mut comp, &artist_name, reason.clone());
         env.storage().instance().set(&id, &comp);
 
-        // Remove artist info from mapping if present
-        if let Some(addr) = artist_address_to_remove {
-            let artist_info_key = Symbol::new(&env, "artist_info");
-            let mut all_info: Map<Address, ArtistInfo> = env
-                .storage()
-                .instance()
-                .get(&artist_info_key)
-                .unwrap_or(Map::new(&env));
-            all_info.remove(addr);
-            env.storage().instance().set(&artist_info_key, &all_info);
-        }
+        env.events()
+            .publish((Symbol::new(&env, "disqualified"), id), (artist_name, reason));
     }
     ///
     /// Remove a registered artist from the global artist registry {Only admin can remove}
@@ -513,787 +997,3785 @@ impl CompetitionContract {
         env.deployer().update_current_contract_wasm(new_wasm_hash);
     }
 
-    /// ***{User Write Fn for Art Competition}***
-    ///
-    /// Vote for an artist in a competition (one vote per wallet)
-    pub fn vote(env: Env, id: String, from: Address, artist: String) {
-        let mut comp: Competition = env.storage().instance().get(&id).unwrap();
+    /// Set how long winners have to claim their prizes, 0 disables expiry {Only admins can set}
+    pub fn set_claim_window(env: Env, from: Address, claim_window: u64) {
+        Self::require_admin(&env, &from, "Only admins can set claim window");
 
-        // Authenticate first before any external calls
-        from.require_auth();
+        env.storage()
+            .instance()
+            .set(&Symbol::new(&env, "claim_window"), &claim_window);
+    }
 
-        // Use the voting eligibility check
-        let eligibility = Self::check_voting_eligibility(env.clone(), id.clone(), from.clone());
-        assert!(eligibility.can_vote, "Not eligible to vote");
-        assert!(!eligibility.has_voted, "Already voted");
+    /// Set the reward in competition token base units paid to whoever calls `finalize`,
+    /// 0 disables it {Only admins can set}
+    pub fn set_keeper_reward(env: Env, from: Address, keeper_reward: i128) {
+        Self::require_admin(&env, &from, "Only admins can set keeper reward");
 
-        // Verify artist exists
-        assert!(
-            comp.artists.iter().any(|(_, name)| name == artist),
-            "Invalid artist"
-        );
+        assert!(keeper_reward >= 0, "Invalid keeper reward");
 
-        // Update vote_log before any external calls
-        comp.vote_log.set(from.clone(), artist.clone());
+        env.storage()
+            .instance()
+            .set(&Symbol::new(&env, "keeper_reward"), &keeper_reward);
+    }
 
-        // Record the vote
-        let current_votes = comp.votes.get(artist.clone()).unwrap_or(0);
-        comp.votes.set(artist.clone(), current_votes + 1);
+    /// Withdraw prizes left unclaimed after the claim deadline, a linked successor receives them
+    /// instead of the admin {Only admins can reclaim}
+    pub fn reclaim_expired_prizes(env: Env, id: String, from: Address) {
+        let mut comp: Competition = env.storage().instance().get(&id).unwrap();
 
-        // Store vote history using the competition's vote log directly
-        // We'll store vote history within the competition structure by extending it
-        let vote_record = VoteHistory {
-            voter: from,
-            artist,
-            timestamp: env.ledger().timestamp(),
-        };
+        Self::require_admin(&env, &from, "Only admins can reclaim prizes");
 
-        // Get existing vote history for this competition
-        let vote_history_key = Symbol::new(&env, "vote_hist");
-        let mut all_vote_history: Map<String, Vec<VoteHistory>> = env
+        let now = env.ledger().timestamp();
+        assert!(
+            comp.state == CompetitionState::Paid
+                && comp.claim_deadline != 0
+                && now > comp.claim_deadline,
+            "Claim period not expired"
+        );
+
+        let pending_key = Symbol::new(&env, "pending_payouts");
+        let mut all_pending: Map<String, Vec<PendingPayout>> = env
             .storage()
             .instance()
-            .get(&vote_history_key)
+            .get(&pending_key)
             .unwrap_or(Map::new(&env));
+        let payouts = all_pending.get(id.clone()).unwrap_or(Vec::new(&env));
+
+        let mut unclaimed: Map<Address, i128> = Map::new(&env);
+        for payout in payouts.iter() {
+            Self::set_payout_status(&env, &id, &payout.artist, &payout.token, PayoutStatus::Reclaimed);
+            let amount = unclaimed.get(payout.token.clone()).unwrap_or(0);
+            unclaimed.set(
+                payout.token,
+                amount.checked_add(payout.amount).expect("Overflow in unclaimed"),
+            );
+        }
 
-        let mut comp_history = all_vote_history.get(id.clone()).unwrap_or(Vec::new(&env));
-        comp_history.push_back(vote_record);
-        all_vote_history.set(id.clone(), comp_history);
-
-        env.storage()
+        // Vesting prizes not withdrawn by the deadline are reclaimed too
+        let vesting_key = Symbol::new(&env, "vesting");
+        let mut all_positions: Map<String, Vec<VestingPosition>> = env
+            .storage()
             .instance()
-            .set(&vote_history_key, &all_vote_history);
-        env.storage().instance().set(&id, &comp);
-    }
-    ///
-    /// Fund the competition pot
-    pub fn fund_pot(env: Env, id: String, from: Address, amount: u64) {
-        let mut comp: Competition = env.storage().instance().get(&id).unwrap();
-
-        from.require_auth();
-
-        // Get token decimals
-        let decimals: u32 =
-            env.invoke_contract(&comp.token, &Symbol::new(&env, "decimals"), vec![&env]);
-        let multiplier = 10u64.checked_pow(decimals).expect("Overflow in decimals");
-        let amount_stroop = amount
-            .checked_mul(multiplier)
-            .expect("Overflow in fund_pot");
-
-        // Update pot before external call
-        comp.pot = comp.pot.checked_add(amount).expect("Overflow in pot");
-        env.storage().instance().set(&id, &comp);
+            .get(&vesting_key)
+            .unwrap_or(Map::new(&env));
+        let mut positions = all_positions.get(id.clone()).unwrap_or(Vec::new(&env));
+        for (i, position) in positions.clone().iter().enumerate() {
+            if position.claimed < position.total {
+                Self::set_payout_status(&env, &id, &position.artist, &position.token, PayoutStatus::Reclaimed);
+            }
+            let amount = unclaimed.get(position.token.clone()).unwrap_or(0);
+            unclaimed.set(
+                position.token.clone(),
+                amount
+                    .checked_add(position.total - position.claimed)
+                    .expect("Overflow in unclaimed"),
+            );
+            let mut updated = position.clone();
+            updated.total = updated.claimed;
+            positions.set(i as u32, updated);
+        }
+        all_positions.set(id.clone(), positions);
 
-        // Transfer tokens from sender to contract
-        env.invoke_contract::<()>(
-            &comp.token,
-            &symbol_short!("transfer"),
-            vec![
-                &env,
-                from.into_val(&env),
-                env.current_contract_address().into_val(&env),
-                (amount_stroop as i128).into_val(&env),
-            ],
+        // Voter rewards share the prize claim deadline
+        let voter_reward_key = Symbol::new(&env, "voter_rwd");
+        let mut all_pools: Map<String, VoterRewardPool> = env
+            .storage()
+            .instance()
+            .get(&voter_reward_key)
+            .unwrap_or(Map::new(&env));
+        if let Some(mut pool) = all_pools.get(id.clone()) {
+            let unclaimed_voters = pool.eligible_voters - pool.claimed.len();
+            for (token, per_voter) in pool.per_voter.iter() {
+                let amount = unclaimed.get(token.clone()).unwrap_or(0);
+                unclaimed.set(
+                    token,
+                    amount
+                        .checked_add(per_voter * unclaimed_voters as i128)
+                        .expect("Overflow in unclaimed"),
+                );
+            }
+            pool.eligible_voters = pool.claimed.len();
+            all_pools.set(id.clone(), pool);
+        }
+        assert!(
+            unclaimed.values().iter().any(|amount| amount > 0),
+            "No unclaimed prizes"
         );
-    }
-    ///
-    /// Pays the competition winners
-    pub fn pay_winners(env: Env, id: String) {
-        let mut comp: Competition = env.storage().instance().get(&id).unwrap();
-
-        let now = env.ledger().timestamp();
-        assert!(now > comp.vote_end, "Voting still active");
 
-        // If not finalized yet, determine the winner first
-        if !comp.finalized {
-            Self::internal_finalize(&env, &mut comp);
-            env.storage().instance().set(&id, &comp);
+        // Clear pending payouts, vesting positions and voter rewards and update pots before external calls
+        all_pending.set(id.clone(), Vec::new(&env));
+        env.storage().instance().set(&pending_key, &all_pending);
+        env.storage().instance().set(&vesting_key, &all_positions);
+        env.storage().instance().set(&voter_reward_key, &all_pools);
+        for (token, amount) in unclaimed.iter() {
+            let pot = comp.pots.get(token.clone()).unwrap_or(0);
+            comp.pots
+                .set(token, pot.checked_sub(amount).expect("Underflow in pot"));
         }
+        env.storage().instance().set(&id, &comp);
 
-        // Always attempt prize distribution if there's a pot and winner
-        // This allows to distribute prizes even after auto-finalization
-        if comp.pot == 0 || comp.winner.is_none() {
+        // A linked successor takes the unclaimed prizes instead of the admin
+        if let Some(successor) = comp.successor {
+            for (token, amount) in unclaimed.iter() {
+                Self::roll_over(&env, &id, &successor, &token, amount);
+            }
             return;
         }
 
-        let decimals: u32 = match env.try_invoke_contract::<u32, soroban_sdk::InvokeError>(
-            &comp.token,
-            &Symbol::new(&env, "decimals"),
-            vec![&env],
-        ) {
-            Ok(Ok(d)) => d,
-            Ok(Err(_)) | Err(_) => {
-                // Winner already determined, just return
-                return;
+        for (token, amount) in unclaimed.iter() {
+            if amount > 0 {
+                env.invoke_contract::<()>(
+                    &token,
+                    &symbol_short!("transfer"),
+                    vec![
+                        &env,
+                        env.current_contract_address().into_val(&env),
+                        from.into_val(&env),
+                        amount.into_val(&env),
+                    ],
+                );
             }
-        };
+        }
+    }
 
-        let multiplier = 10u64.pow(decimals);
+    /// Approve a token that sponsors can use to fund any competition's pot {Only admins can approve}
+    pub fn approve_pot_token(env: Env, from: Address, token: Address) {
+        Self::require_admin(&env, &from, "Only admins can approve pot tokens");
 
-        // Collect all artists with their votes and sort them for prize distribution
-        let mut artist_votes = Vec::new(&env);
-        for (_, artist_name) in comp.artists.iter() {
-            let votes = comp.votes.get(artist_name.clone()).unwrap_or(0);
-            artist_votes.push_back((artist_name.clone(), votes));
-        }
+        let pot_tokens_key = Symbol::new(&env, "pot_tokens");
+        let mut pot_tokens: Vec<Address> = env
+            .storage()
+            .instance()
+            .get(&pot_tokens_key)
+            .unwrap_or(Vec::new(&env));
+        assert!(!pot_tokens.contains(&token), "Token already approved");
+        pot_tokens.push_back(token);
+        env.storage().instance().set(&pot_tokens_key, &pot_tokens);
+    }
 
-        // Sort by votes (descending) - manual bubble sort
-        let len = artist_votes.len();
-        for i in 0..len {
-            for j in 0..(len - 1 - i) {
-                let current_votes = artist_votes.get(j).unwrap().1;
-                let next_votes = artist_votes.get(j + 1).unwrap().1;
-                if current_votes < next_votes {
-                    let temp = artist_votes.get(j).unwrap();
-                    let next = artist_votes.get(j + 1).unwrap();
-                    artist_votes.set(j, next);
-                    artist_votes.set(j + 1, temp);
-                }
-            }
-        }
+    /// Remove a token from the approved pot tokens, existing pots are unaffected {Only admins can remove}
+    pub fn remove_pot_token(env: Env, from: Address, token: Address) {
+        Self::require_admin(&env, &from, "Only admins can remove pot tokens");
 
-        // Group artists by vote count
-        let mut grouped = Vec::new(&env);
-        let mut current_votes = None;
-        let mut current_group = Vec::new(&env);
+        let pot_tokens_key = Symbol::new(&env, "pot_tokens");
+        let mut pot_tokens: Vec<Address> = env
+            .storage()
+            .instance()
+            .get(&pot_tokens_key)
+            .unwrap_or(Vec::new(&env));
+        let index = pot_tokens
+            .first_index_of(&token)
+            .expect("Token not approved");
+        pot_tokens.remove(index);
+        env.storage().instance().set(&pot_tokens_key, &pot_tokens);
+    }
 
-        for (i, (artist_symbol, votes)) in artist_votes.iter().enumerate() {
-            if i == 0 {
-                current_votes = Some(votes);
-                current_group.push_back(artist_symbol);
-            } else {
-                let prev_votes = current_votes.unwrap();
-                if votes == prev_votes {
-                    current_group.push_back(artist_symbol);
-                } else {
-                    grouped.push_back((prev_votes, current_group.clone()));
-                    current_group = Vec::new(&env);
-                    current_group.push_back(artist_symbol);
-                    current_votes = Some(votes);
-                }
-            }
-        }
-        if let Some(count) = current_votes {
-            grouped.push_back((count, current_group));
-        }
+    /// Set the platform fee in basis points taken from new competitions' pots {Only admins can set}
+    pub fn set_platform_fee(env: Env, from: Address, fee_bps: u32, fee_mode: FeeMode) {
+        Self::require_admin(&env, &from, "Only admins can set platform fee");
 
-        let mut rank_index = 0usize;
-        let share = comp.share_ratio.clone();
-        let mut total_paid = 0u64;
+        assert!(fee_bps <= 10_000, "Fee cannot exceed 10000 bps");
 
-        for group_data in grouped.iter() {
-            let (votes_in_group, group_artists) = group_data;
-            if votes_in_group == 0 {
-                break;
-            }
+        env.storage()
+            .instance()
+            .set(&Symbol::new(&env, "fee_bps"), &fee_bps);
+        env.storage()
+            .instance()
+            .set(&Symbol::new(&env, "fee_mode"), &fee_mode);
+    }
 
-            let group_size = group_artists.len();
-            let mut combined_share = 0u64;
-            for offset in 0..group_size {
-                let index = rank_index + offset as usize;
-                if index < share.len().try_into().unwrap() {
-                    combined_share += share.get(index as u32).unwrap() as u64;
-                }
-            }
-            if combined_share == 0 {
-                break;
-            }
+    /// Set the treasury address that receives platform fees {Only admins can set}
+    pub fn set_treasury(env: Env, from: Address, treasury: Address) {
+        Self::require_admin(&env, &from, "Only admins can set treasury");
 
-            let share_per_artist = combined_share / (group_size as u64);
-            for artist_sym in group_artists.iter() {
-                for (addr, name) in comp.artists.iter() {
-                    if name == artist_sym {
-                        let amt_lumen = comp.pot * share_per_artist / 100;
-                        let amt_stroop = amt_lumen * multiplier;
-                        if amt_stroop > 0 {
-                            match env.try_invoke_contract::<(), soroban_sdk::InvokeError>(
-                                &comp.token,
-                                &symbol_short!("transfer"),
-                                vec![
-                                    &env,
-                                    env.current_contract_address().into_val(&env),
-                                    addr.into_val(&env),
-                                    (amt_stroop as i128).into_val(&env),
-                                ],
-                            ) {
-                                Ok(Ok(())) => {
-                                    total_paid += amt_lumen;
-                                }
-                                Ok(Err(_)) | Err(_) => {
-                                    // Transfer failed, continue with next artist
-                                }
-                            }
-                        }
-                        break;
-                    }
-                }
-            }
-            rank_index += group_size as usize;
-            if rank_index >= share.len().try_into().unwrap() {
-                break;
-            }
-        }
+        env.storage()
+            .instance()
+            .set(&Symbol::new(&env, "treasury"), &treasury);
+    }
 
-        // Handle leftover distribution proportionally among actual winners
-        let leftover = comp.pot - total_paid;
-        if leftover > 0 && artist_votes.len() > 0 {
-            // Calculate total share percentage used by actual winners
-            let mut total_winner_share = 0u64;
-            let winners_count = artist_votes
-                .iter()
-                .take_while(|(_, votes)| *votes > 0)
-                .count()
-                .min(share.len().try_into().unwrap());
+    /// Send the accrued platform fees of a token to the treasury {Only admins or treasury can withdraw}
+    pub fn withdraw_treasury(env: Env, from: Address, token: Address) {
+        Self::require_not_paused(&env);
 
-            for i in 0..winners_count {
-                if i < share.len().try_into().unwrap() {
-                    total_winner_share += share.get(i as u32).unwrap() as u64;
-                }
-            }
+        let treasury: Address = env
+            .storage()
+            .instance()
+            .get(&Symbol::new(&env, "treasury"))
+            .expect("Treasury not set");
+        let admin1: Address = env
+            .storage()
+            .instance()
+            .get(&Symbol::new(&env, "admin1"))
+            .unwrap();
+        let admin2: Address = env
+            .storage()
+            .instance()
+            .get(&Symbol::new(&env, "admin2"))
+            .unwrap();
+        assert!(
+            from == admin1 || from == admin2 || from == treasury,
+            "Only admins or treasury can withdraw fees"
+        );
 
-            if total_winner_share > 0 {
-                // Distribute leftover proportionally based on their original share
-                for i in 0..winners_count {
-                    if i < artist_votes.len().try_into().unwrap()
-                        && artist_votes.get(i.try_into().unwrap()).unwrap().1 > 0
-                        && i < share.len().try_into().unwrap()
-                    {
-                        let artist_name =
-                            artist_votes.get(i.try_into().unwrap()).unwrap().0.clone();
-                        let artist_share = share.get(i as u32).unwrap() as u64;
-
-                        // Calculate proportional share of leftover
-                        let proportional_amount = (leftover * artist_share) / total_winner_share;
-
-                        if proportional_amount > 0 {
-                            let mut artist_address: Option<Address> = None;
-                            for (addr, name) in comp.artists.iter() {
-                                if name == artist_name {
-                                    artist_address = Some(addr);
-                                    break;
-                                }
-                            }
-                            if let Some(addr) = artist_address {
-                                let amt_stroop = proportional_amount * multiplier;
-                                if amt_stroop > 0 {
-                                    let _ = env
-                                        .try_invoke_contract::<(), soroban_sdk::InvokeError>(
-                                            &comp.token,
-                                            &symbol_short!("transfer"),
-                                            vec![
-                                                &env,
-                                                env.current_contract_address().into_val(&env),
-                                                addr.into_val(&env),
-                                                (amt_stroop as i128).into_val(&env),
-                                            ],
-                                        );
-                            }
-                            }
-                        }
-                    }
-                }
-            }
-        }
+        from.require_auth();
 
-        // Update pot to reflect distributed prizes
-        comp.pot = 0; // Set to 0, prevent double distribution
-        env.storage().instance().set(&id, &comp);
+        let treasury_key = Symbol::new(&env, "treasury_bal");
+        let mut balances: Map<Address, i128> = env
+            .storage()
+            .instance()
+            .get(&treasury_key)
+            .unwrap_or(Map::new(&env));
+        let amount = balances.get(token.clone()).unwrap_or(0);
+        assert!(amount > 0, "No fees to withdraw");
+
+        // Update balance before external call
+        balances.set(token.clone(), 0);
+        env.storage().instance().set(&treasury_key, &balances);
+
+        env.invoke_contract::<()>(
+            &token,
+            &symbol_short!("transfer"),
+            vec![
+                &env,
+                env.current_contract_address().into_val(&env),
+                treasury.into_val(&env),
+                amount.into_val(&env),
+            ],
+        );
     }
-    ///***{Artist Write Fn for Art Competition}***
-    ///
-    /// Submit an artist to the competition
-    pub fn submit_art(
+
+    /// Reserve a percentage of a competition's pot for its voters before voting starts {Only admins can set}
+    pub fn set_voter_reward(
         env: Env,
         id: String,
-        artist_address: Address,
-        artist_name: String,
-        artwork_name: String,
-        description: String,
-        img_url: String,
+        from: Address,
+        voter_reward_pct: u32,
+        voter_reward_mode: VoterRewardMode,
     ) {
-        artist_address.require_auth();
+        let mut comp: Competition = env.storage().instance().get(&id).unwrap();
 
-        let now = env.ledger().timestamp();
+        Self::require_admin(&env, &from, "Only admins can set voter rewards");
 
-        // Check if competition exists
-        let mut comp: Competition = match env.storage().instance().get(&id) {
-            Some(competition) => competition,
-            None => panic!("Competition not found"),
-        };
+        assert!(voter_reward_pct <= 100, "Voter reward cannot exceed 100%");
+        assert!(
+            env.ledger().timestamp() < comp.vote_start,
+            "Voting already started"
+        );
 
-        // Check submission window
-        if now < comp.artist_add_start || now > comp.artist_add_end {
-            panic!("Submission window closed");
-        }
+        comp.voter_reward_pct = voter_reward_pct;
+        comp.voter_reward_mode = voter_reward_mode;
+        env.storage().instance().set(&id, &comp);
+    }
 
-        // Artists should ensure their symbol names are unique regardless of case
-        // Check for duplicate artist names (exact match)
-        for (_, name) in comp.artists.iter() {
-            if name == artist_name {
-                assert!(false, "Artist name already submitted");
-            }
-        }
+    /// Choose how artists tied on votes are ranked before voting starts {Only admins can set}
+    pub fn set_tie_break(env: Env, id: String, from: Address, tie_break: TieBreak) {
+        let mut comp: Competition = env.storage().instance().get(&id).unwrap();
 
-        // Check for duplicate metadata
-        let mut artworks = comp
-            .artist_metadata
-            .get(artist_name.clone())
-            .unwrap_or_else(|| Vec::new(&env));
+        Self::require_admin(&env, &from, "Only admins can set tie-break");
 
-        // Check for duplicates in the existing artworks
-        for item in artworks.iter() {
-            if item.artwork_name == artwork_name {
-                panic!("Artwork name already exists");
-            }
-            if item.description == description {
-                panic!("Description already exists");
-            }
-            if item.img_url == img_url {
-                panic!("Image URL already exists");
-            }
+        assert!(
+            env.ledger().timestamp() < comp.vote_start,
+            "Voting already started"
+        );
+
+        comp.tie_break = tie_break;
+        env.storage().instance().set(&id, &comp);
+    }
+
+    /// Rank tied artists for a jury tie-break, earlier in `order` ranks higher {Only admins can set}
+    pub fn set_jury_order(env: Env, id: String, from: Address, order: Vec<String>) {
+        let mut comp: Competition = env.storage().instance().get(&id).unwrap();
+
+        Self::require_admin(&env, &from, "Only admins can set jury order");
+
+        assert!(
+            comp.tie_break == TieBreak::JuryDecides,
+            "Competition does not use a jury tie-break"
+        );
+        assert!(env.ledger().timestamp() > comp.vote_end, "Voting still active");
+        Self::sync_state(&env, &mut comp);
+        assert!(
+            comp.state == CompetitionState::Tallying,
+            "Competition already finalized"
+        );
+
+        let mut seen = Vec::new(&env);
+        for name in order.iter() {
+            assert!(
+                comp.artists.iter().any(|(_, artist)| artist == name),
+                "Artist not in competition"
+            );
+            assert!(!seen.contains(&name), "Artist listed twice");
+            seen.push_back(name);
         }
 
-        artworks.push_back(ArtworkMetadata {
-            artwork_name,
-            description,
-            img_url,
-        });
+        comp.jury_order = order;
+        env.storage().instance().set(&id, &comp);
+    }
 
-        comp.artist_metadata.set(artist_name.clone(), artworks);
+    /// Charge artists an entry fee on submission, an amount of 0 means no fee {Only admins can set}
+    ///
+    /// `pot_pct` percent of each fee goes into the competition pot and the rest to the treasury,
+    /// the fee is paid in `token` or in the competition token if none is given
+    pub fn set_entry_fee(
+        env: Env,
+        id: String,
+        from: Address,
+        token: Option<Address>,
+        amount: i128,
+        pot_pct: u32,
+    ) {
+        let mut comp: Competition = env.storage().instance().get(&id).unwrap();
 
-        // Add artist to the list
-        comp.artists.push_back((artist_address, artist_name));
+        Self::require_admin(&env, &from, "Only admins can set entry fees");
 
-        // Save updated competition
+        assert!(amount >= 0, "Invalid entry fee");
+        assert!(pot_pct <= 100, "Pot share cannot exceed 100%");
+        assert!(
+            env.ledger().timestamp() < comp.artist_add_start,
+            "Submissions already started"
+        );
+
+        comp.entry_fee = EntryFee {
+            token: token.unwrap_or(comp.token.clone()),
+            amount,
+            pot_pct,
+        };
         env.storage().instance().set(&id, &comp);
     }
 
-    /// Update artist metadata with optional parameters
-    pub fn update_submission_metadata(
+    /// Cap the number of artists and artworks per artist before submissions close, 0 means no cap.
+    /// Submissions past the artist cap join a waitlist {Only admins can set}
+    pub fn set_submission_limits(
         env: Env,
         id: String,
-        artist_name: String,
         from: Address,
-        artwork_name: Option<String>,
-        description: Option<String>,
+        max_artists: u32,
+        max_artworks_per_artist: u32,
     ) {
         let mut comp: Competition = env.storage().instance().get(&id).unwrap();
 
-        // Verify the caller is the artist who submitted
-        let mut is_artist = false;
-        for (addr, name) in comp.artists.iter() {
-            if name == artist_name && addr == from {
-                is_artist = true;
-                break;
-            }
-        }
-        assert!(is_artist, "Only the submitting artist can update metadata");
+        Self::require_admin(&env, &from, "Only admins can set submission limits");
 
-        from.require_auth();
+        assert!(
+            env.ledger().timestamp() <= comp.artist_add_end,
+            "Submissions already ended"
+        );
+        assert!(
+            max_artists == 0 || max_artists >= comp.artists.len(),
+            "Cap below current artists"
+        );
 
-        // Get current metadata
-        let mut artworks = comp.artist_metadata.get(artist_name.clone()).unwrap();
+        comp.max_artists = max_artists;
+        comp.max_artworks_per_artist = max_artworks_per_artist;
+        Self::promote_waitlist(&env, &id, &mut comp);
+        env.storage().instance().set(&id, &comp);
+    }
 
-        if artworks.len() > 0 {
-            let first_art = artworks.get(0).unwrap();
-            let mut updated_art = ArtworkMetadata {
-                artwork_name: first_art.artwork_name,
-                description: first_art.description,
-                img_url: first_art.img_url,
-            };
-            if let Some(name) = artwork_name {
-                updated_art.artwork_name = name;
-            }
-            if let Some(desc) = description {
-                updated_art.description = desc;
-            }
-            artworks.set(0, updated_art);
-        }
+    /// Only accept submissions from artists with a registered profile, submitted under the
+    /// profile name, set before submissions start {Only admins can set}
+    pub fn set_require_profile(env: Env, id: String, from: Address, required: bool) {
+        let mut comp: Competition = env.storage().instance().get(&id).unwrap();
 
-        comp.artist_metadata.set(artist_name, artworks);
+        Self::require_admin(&env, &from, "Only admins can set profile requirement");
+
+        assert!(
+            env.ledger().timestamp() < comp.artist_add_start,
+            "Submissions already started"
+        );
+
+        comp.require_profile = required;
         env.storage().instance().set(&id, &comp);
     }
 
-    ///***{Artist Write Fn for Artist Registration}***
-    ///
-    /// Add artist profile info (one per address, unique name)
-    pub fn add_artist_info(
+    /// Hold new submissions for review, only approved ones are listed and votable,
+    /// set before submissions start {Only admins can set}
+    pub fn set_moderation(env: Env, id: String, from: Address, moderated: bool) {
+        let mut comp: Competition = env.storage().instance().get(&id).unwrap();
+
+        Self::require_admin(&env, &from, "Only admins can set moderation");
+
+        assert!(
+            env.ledger().timestamp() < comp.artist_add_start,
+            "Submissions already started"
+        );
+
+        comp.moderated = moderated;
+        env.storage().instance().set(&id, &comp);
+    }
+
+    /// Approve or reject a pending submission with an optional reason code, until voting ends.
+    /// Rejected artists can resubmit during the submission window {Only admins can review}
+    pub fn review_submission(
         env: Env,
+        id: String,
         from: Address,
-        name: String,
-        bio: String,
-        img_url: String,
-        website: String,
-        mediums: Vec<Medium>,
-        blockchains: Vec<Network>,
+        artist_name: String,
+        approve: bool,
+        reason: Option<u32>,
     ) {
-        from.require_auth();
-
-        let artist_info_key = Symbol::new(&env, "artist_info");
+        let mut comp: Competition = env.storage().instance().get(&id).unwrap();
 
-        // Load all artist info map: Address -> ArtistInfo
-        let mut all_info: Map<Address, ArtistInfo> = env
-            .storage()
-            .instance()
-            .get(&artist_info_key)
-            .unwrap_or(Map::new(&env));
+        Self::require_admin(&env, &from, "Only admins can review submissions");
+        Self::sync_state(&env, &mut comp);
+        assert!(
+            comp.state == CompetitionState::Submissions || comp.state == CompetitionState::Voting,
+            "Competition not open"
+        );
+        assert!(comp.moderated, "Competition is not moderated");
 
-        // Check for exact name matches instead of case-insensitive
-        for (_, info) in all_info.iter() {
-            if info.name == name {
-                assert!(false, "Artist name already exists");
-            }
-        }
+        let review = comp
+            .reviews
+            .get(artist_name.clone())
+            .expect("Artist not found in competition");
+        assert!(
+            review.status == ReviewStatus::Pending,
+            "Submission not pending"
+        );
 
-        // Save artist info for this address
-        let info = ArtistInfo {
-            registered: true,
-            name,
-            bio,
-            img_url,
-            website,
-            mediums,
-            blockchains,
-            competitions_participated: 0,
-            competitions_won: 0,
+        let status = if approve {
+            ReviewStatus::Approved
+        } else {
+            ReviewStatus::Rejected
         };
-        all_info.set(from.clone(), info);
+        comp.reviews.set(
+            artist_name.clone(),
+            SubmissionReview {
+                status,
+                reason,
+                reviewed_at: env.ledger().timestamp(),
+            },
+        );
+        env.storage().instance().set(&id, &comp);
 
-        env.storage().instance().set(&artist_info_key, &all_info);
+        let event = if approve { "approved" } else { "rejected" };
+        env.events()
+            .publish((Symbol::new(&env, event), id), (artist_name, reason));
     }
 
-    /// Update artist profile info (only by the artist, optional fields)
-    pub fn update_artist_info(
+    /// Set how many reports an address can file, the deposit a report takes in the competition token
+    /// and how many open reports hide a submission from voting, 0 means no limit {Only admins can set}
+    pub fn set_report_policy(
         env: Env,
+        id: String,
         from: Address,
-        name: Option<String>,
-        bio: Option<String>,
-        img_url: Option<String>,
-        website: Option<String>,
-        mediums: Option<Vec<Medium>>,
-        blockchains: Option<Vec<Network>>,
+        max_per_address: u32,
+        deposit: i128,
+        threshold: u32,
+    ) {
+        let mut comp: Competition = env.storage().instance().get(&id).unwrap();
+
+        Self::require_admin(&env, &from, "Only admins can set report policy");
+
+        assert!(Self::is_open(&comp), "Competition not open");
+        assert!(deposit >= 0, "Deposit must not be negative");
+
+        comp.report_policy = ReportPolicy {
+            max_per_address,
+            deposit,
+            threshold,
+        };
+        env.storage().instance().set(&id, &comp);
+    }
+
+    /// Resolve the open reports against a submission, also after finalization. Upheld reports get their
+    /// deposits back and the submission is taken out of the ranking until prizes are allocated, dismissed
+    /// ones forfeit their deposits to the treasury and the submission is shown again {Only admins can resolve}
+    pub fn resolve_reports(env: Env, id: String, from: Address, artist: String, upheld: bool) {
+        let mut comp: Competition = env.storage().instance().get(&id).unwrap();
+
+        Self::require_admin(&env, &from, "Only admins can resolve reports");
+        Self::sync_state(&env, &mut comp);
+        assert!(
+            Self::can_take_funds(&comp) || comp.state == CompetitionState::Paid,
+            "Competition closed"
+        );
+        assert!(
+            comp.artists.iter().any(|(_, name)| name == artist),
+            "Artist not found in competition"
+        );
+
+        Self::close_reports(&env, &id, &comp, Some(&artist), upheld);
+        if let Some(index) = comp.hidden.first_index_of(&artist) {
+            comp.hidden.remove(index);
+        }
+        if upheld && Self::can_take_funds(&comp) && !comp.disqualified.contains_key(artist.clone()) {
+            Self::exclude_from_ranking(&env, &mut comp, &artist, String::from_str(&env, "Reports upheld"));
+        }
+        env.storage().instance().set(&id, &comp);
+
+        env.events()
+            .publish((Symbol::new(&env, "reports_resolved"), id), (artist, upheld));
+    }
+
+    /// Let artists withdraw their submission during voting as well as during submissions,
+    /// set before voting starts {Only admins can set}
+    pub fn set_withdraw_during_voting(env: Env, id: String, from: Address, allowed: bool) {
+        let mut comp: Competition = env.storage().instance().get(&id).unwrap();
+
+        Self::require_admin(&env, &from, "Only admins can set withdrawal policy");
+
+        assert!(
+            env.ledger().timestamp() < comp.vote_start,
+            "Voting already started"
+        );
+
+        comp.withdraw_during_voting = allowed;
+        env.storage().instance().set(&id, &comp);
+    }
+
+    /// Link a follow-up competition that receives any pot this one does not distribute {Only admins can set}
+    pub fn set_successor(env: Env, id: String, from: Address, successor: Option<String>) {
+        let mut comp: Competition = env.storage().instance().get(&id).unwrap();
+
+        Self::require_admin(&env, &from, "Only admins can set successor");
+
+        if let Some(successor_id) = successor.clone() {
+            assert!(successor_id != id, "Competition cannot succeed itself");
+            let next: Competition = env
+                .storage()
+                .instance()
+                .get(&successor_id)
+                .expect("Successor not found");
+            assert!(Self::can_take_funds(&next), "Successor cannot take the pot");
+        }
+
+        comp.successor = successor;
+        env.storage().instance().set(&id, &comp);
+    }
+
+    /// Pay prizes over time, nothing unlocks before `cliff` seconds and everything has
+    /// vested after `duration` seconds, both 0 pays prizes at once {Only admins can set}
+    pub fn set_payout_schedule(env: Env, id: String, from: Address, cliff: u64, duration: u64) {
+        let mut comp: Competition = env.storage().instance().get(&id).unwrap();
+
+        Self::require_admin(&env, &from, "Only admins can set payout schedule");
+
+        assert!(cliff <= duration, "Cliff cannot exceed vesting duration");
+        assert!(
+            comp.state != CompetitionState::Paid,
+            "Prizes already allocated"
+        );
+
+        comp.payout_schedule = PayoutSchedule { cliff, duration };
+        env.storage().instance().set(&id, &comp);
+    }
+
+    /// Pause a competition's submissions, voting, funding and payout {Only admins can pause}
+    pub fn pause_competition(env: Env, id: String, from: Address) {
+        let mut comp: Competition = env.storage().instance().get(&id).unwrap();
+
+        Self::require_admin(&env, &from, "Only admins can pause competitions");
+
+        assert!(comp.paused_at == 0, "Competition already paused");
+
+        comp.paused_at = env.ledger().timestamp();
+        env.storage().instance().set(&id, &comp);
+
+        env.events()
+            .publish((Symbol::new(&env, "paused"), id), comp.paused_at);
+    }
+
+    /// Resume a paused competition, `extend` pushes the remaining phases back by the paused time {Only admins can resume}
+    pub fn resume_competition(env: Env, id: String, from: Address, extend: bool) {
+        let mut comp: Competition = env.storage().instance().get(&id).unwrap();
+
+        Self::require_admin(&env, &from, "Only admins can resume competitions");
+
+        assert!(comp.paused_at != 0, "Competition not paused");
+
+        let paused_at = comp.paused_at;
+        let paused_for = env.ledger().timestamp() - paused_at;
+        if extend {
+            Self::extend_schedule(&mut comp, paused_at, paused_for);
+        }
+        comp.paused_at = 0;
+        env.storage().instance().set(&id, &comp);
+
+        env.events()
+            .publish((Symbol::new(&env, "resumed"), id), paused_for);
+    }
+
+    /// Pause every write except admin calls across all competitions {Only admins can pause}
+    pub fn pause_all(env: Env, from: Address) {
+        Self::require_admin(&env, &from, "Only admins can pause the contract");
+
+        assert!(!Self::is_paused(env.clone()), "Contract already paused");
+
+        let now = env.ledger().timestamp();
+        env.storage()
+            .instance()
+            .set(&Symbol::new(&env, "paused_at"), &now);
+
+        env.events()
+            .publish((Symbol::new(&env, "global_paused"),), now);
+    }
+
+    /// Lift the global pause, `extend` pushes the remaining phases and prize claim deadline of every
+    /// competition back by the paused time, competitions paused on their own are extended when they resume {Only admins can resume}
+    pub fn resume_all(env: Env, from: Address, extend: bool) {
+        Self::require_admin(&env, &from, "Only admins can resume the contract");
+
+        let paused_at_key = Symbol::new(&env, "paused_at");
+        let paused_at: u64 = env.storage().instance().get(&paused_at_key).unwrap_or(0);
+        assert!(paused_at != 0, "Contract not paused");
+
+        let paused_for = env.ledger().timestamp() - paused_at;
+        if extend {
+            let competition_ids: Vec<String> = env
+                .storage()
+                .instance()
+                .get(&Symbol::new(&env, "comp_list"))
+                .unwrap_or(Vec::new(&env));
+            for id in competition_ids.iter() {
+                if let Some(mut comp) = env.storage().instance().get::<String, Competition>(&id) {
+                    // Paid competitions get their claim window back
+                    if comp.paused_at == 0
+                        && !matches!(
+                            comp.state,
+                            CompetitionState::Cancelled | CompetitionState::Archived
+                        )
+                    {
+                        Self::extend_schedule(&mut comp, paused_at, paused_for);
+                        env.storage().instance().set(&id, &comp);
+                    }
+                }
+            }
+        }
+        env.storage().instance().remove(&paused_at_key);
+
+        env.events()
+            .publish((Symbol::new(&env, "global_resumed"),), paused_for);
+    }
+
+    /// Create a template for a recurring competition, editions are named like "weekly-042" {Only admins can create}
+    ///
+    /// Each edition opens submissions for `submission_duration` seconds, waits `voting_gap`
+    /// seconds, then votes for `voting_duration` seconds. Editions start `cadence` seconds
+    /// apart from `first_start`, or as soon as they are started if that time has passed.
+    pub fn create_series(
+        env: Env,
+        from: Address,
+        id: String,
+        id_description: String,
+        token: Address,
+        min_vote_tokens: i128,
+        share_ratio: Vec<u32>,
+        schedule: SeriesSchedule,
+        first_start: u64,
     ) {
+        Self::require_admin(&env, &from, "Only admins can create series");
+
+        assert!(!id.is_empty() && id.len() <= 50, "Invalid series id");
+        assert!(min_vote_tokens >= 0, "Invalid minimum vote tokens");
+        assert!(
+            schedule.submission_duration > 0
+                && schedule.voting_gap > 0
+                && schedule.voting_duration > 0,
+            "Invalid phase durations"
+        );
+        assert!(schedule.cadence > 0, "Invalid cadence");
+        assert!(!share_ratio.is_empty(), "Invalid share ratio");
+        let mut total_share = 0u32;
+        for share in share_ratio.iter() {
+            total_share += share;
+        }
+        assert!(total_share <= 100, "Share ratio cannot exceed 100%");
+
+        let series_key = Symbol::new(&env, "series");
+        let mut all_series: Map<String, CompetitionSeries> = env
+            .storage()
+            .instance()
+            .get(&series_key)
+            .unwrap_or(Map::new(&env));
+        assert!(!all_series.contains_key(id.clone()), "Series ID already exists");
+
+        all_series.set(
+            id.clone(),
+            CompetitionSeries {
+                id,
+                id_description,
+                token,
+                min_vote_tokens,
+                share_ratio,
+                schedule,
+                next_start: first_start,
+                editions: Vec::new(&env),
+                active: true,
+            },
+        );
+        env.storage().instance().set(&series_key, &all_series);
+    }
+
+    /// Stop or restart new editions of a series {Only admins can set}
+    pub fn set_series_active(env: Env, from: Address, id: String, active: bool) {
+        Self::require_admin(&env, &from, "Only admins can update series");
+
+        let series_key = Symbol::new(&env, "series");
+        let mut all_series: Map<String, CompetitionSeries> = env
+            .storage()
+            .instance()
+            .get(&series_key)
+            .unwrap_or(Map::new(&env));
+        let mut series = all_series.get(id.clone()).expect("Series not found");
+
+        series.active = active;
+        all_series.set(id, series);
+        env.storage().instance().set(&series_key, &all_series);
+    }
+
+    /// ***{User Write Fn for Art Competition}***
+    ///
+    /// Start the next edition of a series once the previous one has ended, anyone can call it
+    pub fn start_next_edition(env: Env, series_id: String) -> String {
+        Self::require_not_paused(&env);
+
+        let series_key = Symbol::new(&env, "series");
+        let mut all_series: Map<String, CompetitionSeries> = env
+            .storage()
+            .instance()
+            .get(&series_key)
+            .unwrap_or(Map::new(&env));
+        let mut series = all_series.get(series_id.clone()).expect("Series not found");
+        assert!(series.active, "Series not active");
+
+        let now = env.ledger().timestamp();
+        if let Some(previous_id) = series.editions.last() {
+            let previous: Competition = env.storage().instance().get(&previous_id).unwrap();
+            assert!(now > previous.vote_end, "Previous edition still running");
+        }
+
+        let artist_add_start = series.next_start.max(now);
+        let artist_add_end = artist_add_start + series.schedule.submission_duration;
+        let vote_start = artist_add_end + series.schedule.voting_gap;
+        let vote_end = vote_start + series.schedule.voting_duration;
+
+        // Skip numbers whose id is already taken, e.g. by a competition created by hand
+        let mut edition = series.editions.len() + 1;
+        let mut id = Self::edition_id(&env, &series_id, edition);
+        while env.storage().instance().has(&id) {
+            edition += 1;
+            id = Self::edition_id(&env, &series_id, edition);
+        }
+        let mut comp = Self::store_new_competition(
+            &env,
+            id.clone(),
+            series.id_description.clone(),
+            artist_add_start,
+            artist_add_end,
+            vote_start,
+            vote_end,
+            series.token.clone(),
+            series.min_vote_tokens,
+            true,
+        );
+
+        comp.share_ratio = series.share_ratio.clone();
+        env.storage().instance().set(&id, &comp);
+
+        series.next_start = artist_add_start + series.schedule.cadence;
+        series.editions.push_back(id.clone());
+        all_series.set(series_id, series);
+        env.storage().instance().set(&series_key, &all_series);
+
+        id
+    }
+    ///
+    /// Vote for an artist in a competition (one vote per wallet)
+    pub fn vote(env: Env, id: String, from: Address, artist: String) {
+        Self::cast_vote(&env, id, from, artist, None);
+    }
+    ///
+    /// Vote for one of an artist's artworks, the vote also counts for the artist (one vote per wallet)
+    pub fn vote_for_artwork(env: Env, id: String, from: Address, artist: String, artwork_name: String) {
+        Self::cast_vote(&env, id, from, artist, Some(artwork_name));
+    }
+    ///
+    /// Report a submission for plagiarism, NSFW content or a rule violation while the competition is open,
+    /// taking the competition's report deposit in its token
+    pub fn report(env: Env, id: String, artist: String, reason: ReportReason, from: Address) {
+        let mut comp: Competition = env.storage().instance().get(&id).unwrap();
+        Self::require_running(&env, &comp);
+        Self::sync_state(&env, &mut comp);
+
+        from.require_auth();
+
+        assert!(
+            comp.state == CompetitionState::Submissions || comp.state == CompetitionState::Voting,
+            "Competition not open"
+        );
+        assert!(
+            comp.artists.iter().any(|(_, name)| name == artist),
+            "Invalid artist"
+        );
+
+        let reports_key = Symbol::new(&env, "reports");
+        let mut all_reports: Map<String, Vec<Report>> = env
+            .storage()
+            .instance()
+            .get(&reports_key)
+            .unwrap_or(Map::new(&env));
+        let mut reports = all_reports.get(id.clone()).unwrap_or(Vec::new(&env));
+
+        let mut filed = 0u32;
+        let mut open = 0u32;
+        for item in reports.iter() {
+            if item.reporter == from {
+                filed += 1;
+                assert!(
+                    item.resolved || item.artist != artist,
+                    "Already reported"
+                );
+            }
+            if !item.resolved && item.artist == artist {
+                open += 1;
+            }
+        }
+        let policy = comp.report_policy.clone();
+        assert!(
+            policy.max_per_address == 0 || filed < policy.max_per_address,
+            "Report limit reached"
+        );
+
+        reports.push_back(Report {
+            reporter: from.clone(),
+            artist: artist.clone(),
+            reason,
+            deposit: policy.deposit,
+            timestamp: env.ledger().timestamp(),
+            resolved: false,
+        });
+        all_reports.set(id.clone(), reports);
+        env.storage().instance().set(&reports_key, &all_reports);
+
+        env.events().publish(
+            (Symbol::new(&env, "reported"), id.clone()),
+            (artist.clone(), reason),
+        );
+
+        // Hide the submission from voting once enough reports are open
+        if policy.threshold > 0 && open + 1 >= policy.threshold && !comp.hidden.contains(&artist) {
+            comp.hidden.push_back(artist.clone());
+            env.events()
+                .publish((Symbol::new(&env, "hidden"), id.clone()), artist);
+        }
+        env.storage().instance().set(&id, &comp);
+
+        // Collect the deposit after saving to avoid reentrancy
+        if policy.deposit > 0 {
+            env.invoke_contract::<()>(
+                &comp.token,
+                &symbol_short!("transfer"),
+                vec![
+                    &env,
+                    from.into_val(&env),
+                    env.current_contract_address().into_val(&env),
+                    policy.deposit.into_val(&env),
+                ],
+            );
+        }
+    }
+    ///
+    /// Fund the competition pot with its token or an approved pot token, `amount` is in the token's base units
+    pub fn fund_pot(env: Env, id: String, from: Address, token: Address, amount: i128) {
+        let mut comp: Competition = env.storage().instance().get(&id).unwrap();
+        Self::require_running(&env, &comp);
+
+        from.require_auth();
+
+        assert!(amount > 0, "Amount must be positive");
+        assert!(
+            comp.state != CompetitionState::Cancelled,
+            "Competition cancelled"
+        );
+        assert!(Self::can_take_funds(&comp), "Prizes already allocated");
+        Self::sync_state(&env, &mut comp);
+
+        // The competition token is always accepted, other tokens must be approved by admins
+        if token != comp.token {
+            let pot_tokens: Vec<Address> = env
+                .storage()
+                .instance()
+                .get(&Symbol::new(&env, "pot_tokens"))
+                .unwrap_or(Vec::new(&env));
+            assert!(pot_tokens.contains(&token), "Token not approved for pots");
+        }
+
+        // Take the platform fee now if the competition charges it on funding,
+        // it is held until prizes are allocated so a cancellation can refund it
+        let mut net_amount = amount;
+        if comp.fee_mode == FeeMode::OnFund {
+            let fee = Self::fee_for(&comp, amount);
+            let held = comp.fees_held.get(token.clone()).unwrap_or(0);
+            comp.fees_held.set(token.clone(), held + fee);
+            net_amount -= fee;
+        }
+
+        // Update pot and sponsor ledger before external call
+        let pot = comp.pots.get(token.clone()).unwrap_or(0);
+        comp.pots.set(
+            token.clone(),
+            pot.checked_add(net_amount).expect("Overflow in pot"),
+        );
+        env.storage().instance().set(&id, &comp);
+
+        let now = env.ledger().timestamp();
+        let sponsors_key = Symbol::new(&env, "sponsors");
+        let mut all_sponsors: Map<String, Vec<Sponsor>> = env
+            .storage()
+            .instance()
+            .get(&sponsors_key)
+            .unwrap_or(Map::new(&env));
+        let mut sponsors = all_sponsors.get(id.clone()).unwrap_or(Vec::new(&env));
+        let mut found = false;
+        for (i, sponsor) in sponsors.iter().enumerate() {
+            if sponsor.funder == from && sponsor.token == token {
+                let mut updated = sponsor.clone();
+                updated.amount = updated
+                    .amount
+                    .checked_add(amount)
+                    .expect("Overflow in contribution");
+                updated.last_funded = now;
+                sponsors.set(i as u32, updated);
+                found = true;
+                break;
+            }
+        }
+        if !found {
+            // Reuse the credit details the sponsor already set for another token
+            let mut existing: Option<Sponsor> = None;
+            for sponsor in sponsors.iter() {
+                if sponsor.funder == from {
+                    existing = Some(sponsor);
+                    break;
+                }
+            }
+            sponsors.push_back(Sponsor {
+                funder: from.clone(),
+                token: token.clone(),
+                amount,
+                first_funded: now,
+                last_funded: now,
+                refunded: false,
+                display_name: existing.as_ref().and_then(|e| e.display_name.clone()),
+                logo_url: existing.as_ref().and_then(|e| e.logo_url.clone()),
+                link: existing.as_ref().and_then(|e| e.link.clone()),
+            });
+        }
+        all_sponsors.set(id.clone(), sponsors);
+        env.storage().instance().set(&sponsors_key, &all_sponsors);
+
+        // Transfer tokens from sender to contract
+        env.invoke_contract::<()>(
+            &token,
+            &symbol_short!("transfer"),
+            vec![
+                &env,
+                from.into_val(&env),
+                env.current_contract_address().into_val(&env),
+                amount.into_val(&env),
+            ],
+        );
+    }
+    ///
+    /// Finalize a competition once voting has ended, anyone can call it
+    ///
+    /// Records the ranking, winner and win counts. The caller receives the keeper reward from the
+    /// competition token pot when one is set and the pot covers it, so a finalized competition
+    /// can no longer be cancelled and refunded.
+    pub fn finalize(env: Env, id: String, keeper: Address) {
+        let mut comp: Competition = env.storage().instance().get(&id).unwrap();
+        Self::require_running(&env, &comp);
+
+        keeper.require_auth();
+
+        assert!(env.ledger().timestamp() > comp.vote_end, "Voting still active");
+        Self::sync_state(&env, &mut comp);
+        assert!(
+            comp.state == CompetitionState::Tallying,
+            "Competition already finalized"
+        );
+
+        Self::internal_finalize(&env, &mut comp);
+        assert!(
+            comp.state == CompetitionState::Finalized,
+            "Tie awaiting jury decision"
+        );
+
+        // Pay the keeper from the prize pot before saving so the pot stays accurate
+        let keeper_reward: i128 = env
+            .storage()
+            .instance()
+            .get(&Symbol::new(&env, "keeper_reward"))
+            .unwrap_or(0);
+        let pot = comp.pots.get(comp.token.clone()).unwrap_or(0);
+        let reward = if keeper_reward > 0 && pot >= keeper_reward {
+            keeper_reward
+        } else {
+            0
+        };
+        comp.pots.set(comp.token.clone(), pot - reward);
+        comp.keeper_paid = reward;
+        env.storage().instance().set(&id, &comp);
+
+        if reward > 0 {
+            env.invoke_contract::<()>(
+                &comp.token,
+                &symbol_short!("transfer"),
+                vec![
+                    &env,
+                    env.current_contract_address().into_val(&env),
+                    keeper.into_val(&env),
+                    reward.into_val(&env),
+                ],
+            );
+        }
+
+        env.events()
+            .publish((Symbol::new(&env, "finalized"), id), (keeper, reward));
+    }
+    ///
+    /// Pays the competition winners
+    ///
+    /// Records each winner's prize as a pending payout per token, collected with `claim_prize` or
+    /// `retry_payouts`. Every token's pot is split by `share_ratio` the same way. All amounts are
+    /// token base units and shares are taken from the pot net of the platform fee and the voter
+    /// reward. Each share is `pot * share / 100` rounded down, artists sharing a rank under the
+    /// tie-break policy split their combined share, and the leftover from unused shares goes to the
+    /// winners in proportion to their share.
+    /// The few base units lost to rounding go to the top ranked artist, or roll over to the successor
+    /// if one is linked, so the whole pot is allocated.
+    pub fn pay_winners(env: Env, id: String) {
+        let mut comp: Competition = env.storage().instance().get(&id).unwrap();
+        Self::require_running(&env, &comp);
+
+        let now = env.ledger().timestamp();
+        assert!(now > comp.vote_end, "Voting still active");
+        assert!(
+            comp.state != CompetitionState::Cancelled,
+            "Competition cancelled"
+        );
+
+        // If not finalized yet, determine the winner first
+        Self::sync_state(&env, &mut comp);
+        if comp.state == CompetitionState::Tallying {
+            Self::internal_finalize(&env, &mut comp);
+        }
+        env.storage().instance().set(&id, &comp);
+        assert!(
+            comp.state != CompetitionState::Tallying,
+            "Tie awaiting jury decision"
+        );
+        assert!(
+            comp.state == CompetitionState::Finalized || comp.state == CompetitionState::Paid,
+            "Competition not finalized"
+        );
+
+        // Nobody to pay and no successor, the pot and held fees stay so the competition can be cancelled
+        if comp.winner.is_none() && comp.successor.is_none() {
+            return;
+        }
+
+        // Release the fees held since funding to the treasury
+        if !comp.fees_held.is_empty() {
+            for (token, fee) in comp.fees_held.iter() {
+                Self::accrue_fee(&env, &id, &token, fee);
+            }
+            comp.fees_held = Map::new(&env);
+            env.storage().instance().set(&id, &comp);
+        }
+
+        // Always attempt prize allocation if there's a pot and winner
+        // This allows to allocate prizes even after auto-finalization
+        let has_funds = comp.pots.values().iter().any(|amount| amount > 0);
+        if comp.state == CompetitionState::Paid || !has_funds {
+            return;
+        }
+
+        // Nobody to pay, the pot moves to the successor.
+        // A rolled over pot is settled, the competition can no longer be cancelled and refunded.
+        if let (None, Some(successor)) = (comp.winner.clone(), comp.successor.clone()) {
+            for (token, pot) in comp.pots.clone().iter() {
+                Self::roll_over(&env, &id, &successor, &token, pot);
+                comp.pots.set(token, 0);
+            }
+            Self::set_state(&env, &mut comp, CompetitionState::Paid);
+            env.storage().instance().set(&id, &comp);
+            return;
+        }
+
+        // Group the recorded ranking, artists sharing a rank split their combined share
+        let mut artist_votes = Vec::new(&env);
+        let mut grouped: Vec<(u64, Vec<String>)> = Vec::new(&env);
+        let mut current_rank = 0u32;
+        for ranking in comp.ranking.iter() {
+            artist_votes.push_back((ranking.artist.clone(), ranking.votes));
+            if ranking.rank == current_rank {
+                let last = grouped.len() - 1;
+                let (votes, mut group) = grouped.get(last).unwrap();
+                group.push_back(ranking.artist);
+                grouped.set(last, (votes, group));
+            } else {
+                current_rank = ranking.rank;
+                grouped.push_back((ranking.votes, vec![&env, ranking.artist]));
+            }
+        }
+
+        // Count the voters sharing the voter reward
+        let mut eligible_voters = 0u32;
+        if comp.voter_reward_pct > 0 {
+            for record in Self::get_vote_history(env.clone(), id.clone()).iter() {
+                if comp.voter_reward_mode == VoterRewardMode::AllVoters
+                    || Self::is_winner(&comp, &record.artist)
+                {
+                    eligible_voters += 1;
+                }
+            }
+        }
+        let mut voter_pool = VoterRewardPool {
+            per_voter: Map::new(&env),
+            eligible_voters,
+            claimed: Vec::new(&env),
+        };
+
+        let mut payouts: Vec<PendingPayout> = Vec::new(&env);
+        let mut records: Vec<PayoutRecord> = Vec::new(&env);
+        for (token, pot) in comp.pots.clone().iter() {
+            let mut pot = pot;
+
+            // Take the platform fee now if the competition charges it on payout, shares use the net pot
+            if comp.fee_mode == FeeMode::OnPayout {
+                let fee = Self::fee_for(&comp, pot);
+                Self::accrue_fee(&env, &id, &token, fee);
+                pot -= fee;
+                comp.pots.set(token.clone(), pot);
+            }
+
+            // Reserve the voter reward, split equally among the eligible voters
+            let mut voter_reserve = 0i128;
+            if eligible_voters > 0 {
+                let per_voter = pot * comp.voter_reward_pct as i128 / 100 / eligible_voters as i128;
+                voter_reserve = per_voter * eligible_voters as i128;
+                voter_pool.per_voter.set(token.clone(), per_voter);
+            }
+
+            let rolled_over = Self::allocate_prizes(
+                &env,
+                &comp,
+                &artist_votes,
+                &grouped,
+                &token,
+                pot - voter_reserve,
+                &mut payouts,
+                &mut records,
+            );
+            comp.pots.set(token.clone(), pot - rolled_over);
+        }
+
+        if eligible_voters > 0 {
+            let voter_reward_key = Symbol::new(&env, "voter_rwd");
+            let mut all_pools: Map<String, VoterRewardPool> = env
+                .storage()
+                .instance()
+                .get(&voter_reward_key)
+                .unwrap_or(Map::new(&env));
+            all_pools.set(id.clone(), voter_pool);
+            env.storage().instance().set(&voter_reward_key, &all_pools);
+        }
+
+        // With a payout schedule the prizes become vesting positions instead of pending payouts
+        if comp.payout_schedule.duration > 0 {
+            let mut positions = Vec::new(&env);
+            for payout in payouts.iter() {
+                positions.push_back(VestingPosition {
+                    artist: payout.artist,
+                    address: payout.address,
+                    token: payout.token,
+                    total: payout.amount,
+                    claimed: 0,
+                    start: now,
+                });
+            }
+            payouts = Vec::new(&env);
+
+            for (i, record) in records.clone().iter().enumerate() {
+                let mut updated = record.clone();
+                updated.status = PayoutStatus::Vesting;
+                records.set(i as u32, updated);
+            }
+
+            let vesting_key = Symbol::new(&env, "vesting");
+            let mut all_positions: Map<String, Vec<VestingPosition>> = env
+                .storage()
+                .instance()
+                .get(&vesting_key)
+                .unwrap_or(Map::new(&env));
+            all_positions.set(id.clone(), positions);
+            env.storage().instance().set(&vesting_key, &all_positions);
+        }
+
+        // Start the claim period once everything has vested, prizes stay in the pot until claimed
+        let claim_window: u64 = env
+            .storage()
+            .instance()
+            .get(&Symbol::new(&env, "claim_window"))
+            .unwrap_or(0);
+        comp.claim_deadline = if claim_window > 0 {
+            now + comp.payout_schedule.duration + claim_window
+        } else {
+            0
+        };
+        Self::set_state(&env, &mut comp, CompetitionState::Paid); // Prevent double allocation
+
+        let pending_key = Symbol::new(&env, "pending_payouts");
+        let mut all_pending: Map<String, Vec<PendingPayout>> = env
+            .storage()
+            .instance()
+            .get(&pending_key)
+            .unwrap_or(Map::new(&env));
+        all_pending.set(id.clone(), payouts);
+        env.storage().instance().set(&pending_key, &all_pending);
+
+        let payouts_key = Symbol::new(&env, "payouts");
+        let mut all_records: Map<String, Vec<PayoutRecord>> = env
+            .storage()
+            .instance()
+            .get(&payouts_key)
+            .unwrap_or(Map::new(&env));
+        all_records.set(id.clone(), records);
+        env.storage().instance().set(&payouts_key, &all_records);
+        env.storage().instance().set(&id, &comp);
+    }
+    ///
+    /// Claim the prizes allocated to an artist in every token, failed transfers stay pending for retry
+    pub fn claim_prize(env: Env, id: String, artist: String) {
+        Self::require_not_paused(&env);
+
+        let mut comp: Competition = env.storage().instance().get(&id).unwrap();
+
+        let pending_key = Symbol::new(&env, "pending_payouts");
+        let mut all_pending: Map<String, Vec<PendingPayout>> = env
+            .storage()
+            .instance()
+            .get(&pending_key)
+            .unwrap_or(Map::new(&env));
+        let payouts = all_pending.get(id.clone()).unwrap_or(Vec::new(&env));
+
+        let mut artist_address: Option<Address> = None;
+        for item in payouts.iter() {
+            if item.artist == artist {
+                artist_address = Some(item.address);
+                break;
+            }
+        }
+        let artist_address = artist_address.expect("No prize to claim");
+
+        artist_address.require_auth();
+
+        let now = env.ledger().timestamp();
+        assert!(
+            comp.claim_deadline == 0 || now <= comp.claim_deadline,
+            "Prize claim expired"
+        );
+
+        let mut remaining = Vec::new(&env);
+        for mut payout in payouts.iter() {
+            if payout.artist != artist {
+                remaining.push_back(payout);
+                continue;
+            }
+
+            // Only deduct from the pot what was actually transferred
+            if Self::try_pay_out(&env, &payout.token, &payout.address, payout.amount) {
+                let pot = comp.pots.get(payout.token.clone()).unwrap_or(0);
+                comp.pots.set(
+                    payout.token.clone(),
+                    pot.checked_sub(payout.amount).expect("Underflow in pot"),
+                );
+                Self::set_payout_status(&env, &id, &payout.artist, &payout.token, PayoutStatus::Paid);
+            } else {
+                Self::set_payout_status(&env, &id, &payout.artist, &payout.token, PayoutStatus::Failed);
+                payout.attempts += 1;
+                remaining.push_back(payout);
+            }
+        }
+        env.storage().instance().set(&id, &comp);
+
+        all_pending.set(id, remaining);
+        env.storage().instance().set(&pending_key, &all_pending);
+    }
+    ///
+    /// Retry every pending payout of a competition, payouts that fail again stay pending
+    pub fn retry_payouts(env: Env, id: String) {
+        Self::require_not_paused(&env);
+
+        let mut comp: Competition = env.storage().instance().get(&id).unwrap();
+
+        let now = env.ledger().timestamp();
+        assert!(
+            comp.claim_deadline == 0 || now <= comp.claim_deadline,
+            "Prize claim expired"
+        );
+
+        let pending_key = Symbol::new(&env, "pending_payouts");
+        let mut all_pending: Map<String, Vec<PendingPayout>> = env
+            .storage()
+            .instance()
+            .get(&pending_key)
+            .unwrap_or(Map::new(&env));
+        let payouts = all_pending.get(id.clone()).unwrap_or(Vec::new(&env));
+        assert!(!payouts.is_empty(), "No pending payouts");
+
+        let mut remaining = Vec::new(&env);
+        for mut payout in payouts.iter() {
+            // Only deduct from the pot what was actually transferred
+            if Self::try_pay_out(&env, &payout.token, &payout.address, payout.amount) {
+                let pot = comp.pots.get(payout.token.clone()).unwrap_or(0);
+                comp.pots.set(
+                    payout.token.clone(),
+                    pot.checked_sub(payout.amount).expect("Underflow in pot"),
+                );
+                Self::set_payout_status(&env, &id, &payout.artist, &payout.token, PayoutStatus::Paid);
+            } else {
+                Self::set_payout_status(&env, &id, &payout.artist, &payout.token, PayoutStatus::Failed);
+                payout.attempts += 1;
+                remaining.push_back(payout);
+            }
+        }
+        env.storage().instance().set(&id, &comp);
+
+        all_pending.set(id, remaining);
+        env.storage().instance().set(&pending_key, &all_pending);
+    }
+    ///
+    /// Withdraw the unlocked part of an artist's vesting prizes in every token
+    pub fn withdraw_vested(env: Env, id: String, artist: String) {
+        Self::require_not_paused(&env);
+
+        let mut comp: Competition = env.storage().instance().get(&id).unwrap();
+
+        let vesting_key = Symbol::new(&env, "vesting");
+        let mut all_positions: Map<String, Vec<VestingPosition>> = env
+            .storage()
+            .instance()
+            .get(&vesting_key)
+            .unwrap_or(Map::new(&env));
+        let mut positions = all_positions.get(id.clone()).unwrap_or(Vec::new(&env));
+
+        let mut artist_address: Option<Address> = None;
+        for position in positions.iter() {
+            if position.artist == artist {
+                artist_address = Some(position.address);
+                break;
+            }
+        }
+        let artist_address = artist_address.expect("No vesting prize");
+
+        artist_address.require_auth();
+
+        let now = env.ledger().timestamp();
+        assert!(
+            comp.claim_deadline == 0 || now <= comp.claim_deadline,
+            "Prize claim expired"
+        );
+
+        // Update positions and pots before external calls
+        let mut releases: Map<Address, i128> = Map::new(&env);
+        for (i, position) in positions.clone().iter().enumerate() {
+            if position.artist != artist {
+                continue;
+            }
+            let releasable =
+                Self::vested_amount(&comp.payout_schedule, &position, now) - position.claimed;
+            if releasable == 0 {
+                continue;
+            }
+
+            let mut updated = position.clone();
+            updated.claimed += releasable;
+            if updated.claimed == updated.total {
+                Self::set_payout_status(&env, &id, &artist, &position.token, PayoutStatus::Paid);
+            }
+            positions.set(i as u32, updated);
+
+            let pot = comp.pots.get(position.token.clone()).unwrap_or(0);
+            comp.pots.set(
+                position.token.clone(),
+                pot.checked_sub(releasable).expect("Underflow in pot"),
+            );
+            let released = releases.get(position.token.clone()).unwrap_or(0);
+            releases.set(position.token, released + releasable);
+        }
+        assert!(!releases.is_empty(), "Nothing vested to withdraw");
+
+        all_positions.set(id.clone(), positions);
+        env.storage().instance().set(&vesting_key, &all_positions);
+        env.storage().instance().set(&id, &comp);
+
+        for (token, amount) in releases.iter() {
+            env.invoke_contract::<()>(
+                &token,
+                &symbol_short!("transfer"),
+                vec![
+                    &env,
+                    env.current_contract_address().into_val(&env),
+                    artist_address.into_val(&env),
+                    amount.into_val(&env),
+                ],
+            );
+        }
+    }
+    ///
+    /// Claim a voter's equal share of the voter reward pool in every token
+    pub fn claim_voter_reward(env: Env, id: String, voter: Address) {
+        Self::require_not_paused(&env);
+
+        let mut comp: Competition = env.storage().instance().get(&id).unwrap();
+
+        voter.require_auth();
+
+        let now = env.ledger().timestamp();
+        assert!(
+            comp.claim_deadline == 0 || now <= comp.claim_deadline,
+            "Prize claim expired"
+        );
+
+        let voter_reward_key = Symbol::new(&env, "voter_rwd");
+        let mut all_pools: Map<String, VoterRewardPool> = env
+            .storage()
+            .instance()
+            .get(&voter_reward_key)
+            .unwrap_or(Map::new(&env));
+        let mut pool = all_pools.get(id.clone()).expect("No voter reward for competition");
+        assert!(!pool.claimed.contains(&voter), "Voter reward already claimed");
+
+        // Check the vote record against the reward mode
+        let mut eligible = false;
+        for record in Self::get_vote_history(env.clone(), id.clone()).iter() {
+            if record.voter == voter {
+                eligible = comp.voter_reward_mode == VoterRewardMode::AllVoters
+                    || Self::is_winner(&comp, &record.artist);
+                break;
+            }
+        }
+        assert!(eligible, "Not eligible for voter reward");
+
+        // Update pool and pots before external calls
+        pool.claimed.push_back(voter.clone());
+        all_pools.set(id.clone(), pool.clone());
+        env.storage().instance().set(&voter_reward_key, &all_pools);
+        for (token, per_voter) in pool.per_voter.iter() {
+            let pot = comp.pots.get(token.clone()).unwrap_or(0);
+            comp.pots
+                .set(token, pot.checked_sub(per_voter).expect("Underflow in pot"));
+        }
+        env.storage().instance().set(&id, &comp);
+
+        for (token, per_voter) in pool.per_voter.iter() {
+            if per_voter > 0 {
+                env.invoke_contract::<()>(
+                    &token,
+                    &symbol_short!("transfer"),
+                    vec![
+                        &env,
+                        env.current_contract_address().into_val(&env),
+                        voter.into_val(&env),
+                        per_voter.into_val(&env),
+                    ],
+                );
+            }
+        }
+    }
+    ///
+    /// Claim back everything a funder put into a cancelled competition, in every token,
+    /// along with any entry fee whose automatic refund failed
+    pub fn claim_refund(env: Env, id: String, funder: Address) {
+        Self::require_not_paused(&env);
+
+        let mut comp: Competition = env.storage().instance().get(&id).unwrap();
+
+        funder.require_auth();
+
+        let sponsors_key = Symbol::new(&env, "sponsors");
+        let mut all_sponsors: Map<String, Vec<Sponsor>> = env
+            .storage()
+            .instance()
+            .get(&sponsors_key)
+            .unwrap_or(Map::new(&env));
+        let mut sponsors = all_sponsors.get(id.clone()).unwrap_or(Vec::new(&env));
+
+        // Update sponsor ledger and pots before external calls
+        let mut refunds: Map<Address, i128> = Map::new(&env);
+        for (i, sponsor) in sponsors.clone().iter().enumerate() {
+            if comp.state != CompetitionState::Cancelled
+                || sponsor.funder != funder
+                || sponsor.refunded
+            {
+                continue;
+            }
+
+            // The pot and the held fee together cover every contribution
+            let pot = comp.pots.get(sponsor.token.clone()).unwrap_or(0);
+            let held = comp.fees_held.get(sponsor.token.clone()).unwrap_or(0);
+            let from_pot = sponsor.amount.min(pot);
+            let from_fee = sponsor.amount - from_pot;
+            assert!(from_fee <= held, "Insufficient funds for refund");
+            comp.pots.set(sponsor.token.clone(), pot - from_pot);
+            if held > 0 {
+                comp.fees_held.set(sponsor.token.clone(), held - from_fee);
+            }
+
+            let refund = refunds.get(sponsor.token.clone()).unwrap_or(0);
+            refunds.set(sponsor.token.clone(), refund + sponsor.amount);
+            let mut updated = sponsor.clone();
+            updated.refunded = true;
+            sponsors.set(i as u32, updated);
+        }
+
+        // Entry fees were already taken out of the pots when their refund failed
+        let entry_fees_key = Symbol::new(&env, "entry_fees");
+        let mut all_entry_fees: Map<String, Vec<EntryFeePayment>> = env
+            .storage()
+            .instance()
+            .get(&entry_fees_key)
+            .unwrap_or(Map::new(&env));
+        let payments = all_entry_fees.get(id.clone()).unwrap_or(Vec::new(&env));
+        let mut remaining_payments = Vec::new(&env);
+        for payment in payments.iter() {
+            if payment.address == funder && payment.refund_due {
+                let refund = refunds.get(payment.token.clone()).unwrap_or(0);
+                refunds.set(payment.token.clone(), refund + payment.amount);
+            } else {
+                remaining_payments.push_back(payment);
+            }
+        }
+        assert!(!refunds.is_empty(), "Nothing to refund");
+
+        all_sponsors.set(id.clone(), sponsors);
+        env.storage().instance().set(&sponsors_key, &all_sponsors);
+        all_entry_fees.set(id.clone(), remaining_payments);
+        env.storage().instance().set(&entry_fees_key, &all_entry_fees);
+        env.storage().instance().set(&id, &comp);
+
+        for (token, refund) in refunds.iter() {
+            env.invoke_contract::<()>(
+                &token,
+                &symbol_short!("transfer"),
+                vec![
+                    &env,
+                    env.current_contract_address().into_val(&env),
+                    funder.into_val(&env),
+                    refund.into_val(&env),
+                ],
+            );
+        }
+    }
+
+    /// Claim back report deposits whose refund failed when the reports were upheld or the competition cancelled
+    pub fn claim_report_refund(env: Env, id: String, reporter: Address) {
+        Self::require_not_paused(&env);
+
+        let comp: Competition = env.storage().instance().get(&id).unwrap();
+
+        reporter.require_auth();
+
+        // Clear the deposits before the external call
+        let reports_key = Symbol::new(&env, "reports");
+        let mut all_reports: Map<String, Vec<Report>> = env
+            .storage()
+            .instance()
+            .get(&reports_key)
+            .unwrap_or(Map::new(&env));
+        let mut reports = all_reports.get(id.clone()).unwrap_or(Vec::new(&env));
+        let mut refund = 0i128;
+        for (i, mut report) in reports.clone().iter().enumerate() {
+            if report.reporter == reporter && report.resolved && report.deposit > 0 {
+                refund += report.deposit;
+                report.deposit = 0;
+                reports.set(i as u32, report);
+            }
+        }
+        assert!(refund > 0, "Nothing to refund");
+        all_reports.set(id, reports);
+        env.storage().instance().set(&reports_key, &all_reports);
+
+        env.invoke_contract::<()>(
+            &comp.token,
+            &symbol_short!("transfer"),
+            vec![
+                &env,
+                env.current_contract_address().into_val(&env),
+                reporter.into_val(&env),
+                refund.into_val(&env),
+            ],
+        );
+    }
+    ///
+    /// Update how a sponsor is credited on a competition (optional fields)
+    pub fn set_sponsor_info(
+        env: Env,
+        id: String,
+        from: Address,
+        display_name: Option<String>,
+        logo_url: Option<String>,
+        link: Option<String>,
+    ) {
+        Self::require_not_paused(&env);
+
+        from.require_auth();
+
+        let sponsors_key = Symbol::new(&env, "sponsors");
+        let mut all_sponsors: Map<String, Vec<Sponsor>> = env
+            .storage()
+            .instance()
+            .get(&sponsors_key)
+            .unwrap_or(Map::new(&env));
+        let mut sponsors = all_sponsors.get(id.clone()).unwrap_or(Vec::new(&env));
+
+        // A sponsor has one ledger entry per token funded, keep them all in sync
+        let mut found = false;
+        for (i, sponsor) in sponsors.clone().iter().enumerate() {
+            if sponsor.funder != from {
+                continue;
+            }
+            let mut updated = sponsor.clone();
+            if display_name.is_some() {
+                updated.display_name = display_name.clone();
+            }
+            if logo_url.is_some() {
+                updated.logo_url = logo_url.clone();
+            }
+            if link.is_some() {
+                updated.link = link.clone();
+            }
+            sponsors.set(i as u32, updated);
+            found = true;
+        }
+        assert!(found, "Only sponsors can set sponsor info");
+
+        all_sponsors.set(id, sponsors);
+        env.storage().instance().set(&sponsors_key, &all_sponsors);
+    }
+    ///***{Artist Write Fn for Art Competition}***
+    ///
+    /// Submit an artist to the competition
+    pub fn submit_art(
+        env: Env,
+        id: String,
+        artist_address: Address,
+        artist_name: String,
+        artwork_name: String,
+        description: String,
+        img_url: String,
+    ) {
+        artist_address.require_auth();
+
+        let now = env.ledger().timestamp();
+
+        // Check if competition exists
+        let mut comp: Competition = match env.storage().instance().get(&id) {
+            Some(competition) => competition,
+            None => panic!("Competition not found"),
+        };
+
+        assert!(
+            comp.state != CompetitionState::Cancelled,
+            "Competition cancelled"
+        );
+        Self::require_running(&env, &comp);
+        Self::sync_state(&env, &mut comp);
+        assert!(
+            comp.state == CompetitionState::Submissions,
+            "Submission window closed"
+        );
+
+        // Check submission window
+        if now < comp.artist_add_start || now > comp.artist_add_end {
+            panic!("Submission window closed");
+        }
+
+        // Competitions requiring a profile use the registered name as the submission name
+        let artist_name = if comp.require_profile {
+            Self::get_artist_info(env.clone(), artist_address.clone())
+                .expect("Artist profile required")
+                .name
+        } else {
+            artist_name
+        };
+
+        // Artists should ensure their symbol names are unique regardless of case
+        // Check for duplicate artist names (exact match), waitlisted artists included
+        for (_, name) in comp.artists.iter() {
+            if name == artist_name {
+                panic!("Artist name already submitted");
+            }
+        }
+        let waitlist_key = Symbol::new(&env, "waitlist");
+        let mut all_waitlists: Map<String, Vec<WaitlistEntry>> = env
+            .storage()
+            .instance()
+            .get(&waitlist_key)
+            .unwrap_or(Map::new(&env));
+        let mut waitlist = all_waitlists.get(id.clone()).unwrap_or(Vec::new(&env));
+        for entry in waitlist.iter() {
+            if entry.artist_name == artist_name {
+                panic!("Artist name already submitted");
+            }
+        }
+
+        let artwork = ArtworkMetadata {
+            artwork_name,
+            description,
+            img_url,
+        };
+        let entry_fee = comp.entry_fee.clone();
+
+        if comp.max_artists > 0 && comp.artists.len() >= comp.max_artists {
+            // The competition is full, queue the submission and hold its entry fee until a slot frees up
+            waitlist.push_back(WaitlistEntry {
+                address: artist_address.clone(),
+                artist_name: artist_name.clone(),
+                artwork,
+                fee_paid: entry_fee.amount,
+                joined_at: now,
+            });
+            all_waitlists.set(id.clone(), waitlist);
+            env.storage().instance().set(&waitlist_key, &all_waitlists);
+
+            env.events().publish(
+                (Symbol::new(&env, "waitlisted"), id.clone()),
+                artist_name,
+            );
+        } else {
+            Self::admit_artist(
+                &env,
+                &id,
+                &mut comp,
+                artist_address.clone(),
+                artist_name,
+                artwork,
+                entry_fee.amount,
+            );
+        }
+
+        // Save updated competition
+        env.storage().instance().set(&id, &comp);
+
+        // Collect the entry fee after saving to avoid reentrancy
+        if entry_fee.amount > 0 {
+            env.invoke_contract::<()>(
+                &entry_fee.token,
+                &symbol_short!("transfer"),
+                vec![
+                    &env,
+                    artist_address.into_val(&env),
+                    env.current_contract_address().into_val(&env),
+                    entry_fee.amount.into_val(&env),
+                ],
+            );
+        }
+    }
+
+    /// Leave a competition's waitlist and get the entry fee back
+    pub fn leave_waitlist(env: Env, id: String, artist_address: Address) {
+        Self::require_not_paused(&env);
+
+        artist_address.require_auth();
+
+        let comp: Competition = env.storage().instance().get(&id).unwrap();
+
+        let waitlist_key = Symbol::new(&env, "waitlist");
+        let mut all_waitlists: Map<String, Vec<WaitlistEntry>> = env
+            .storage()
+            .instance()
+            .get(&waitlist_key)
+            .unwrap_or(Map::new(&env));
+        let mut waitlist = all_waitlists.get(id.clone()).unwrap_or(Vec::new(&env));
+
+        let index = waitlist
+            .iter()
+            .position(|entry| entry.address == artist_address)
+            .expect("Not on waitlist");
+        let entry = waitlist.get(index as u32).unwrap();
+        waitlist.remove(index as u32);
+        all_waitlists.set(id, waitlist);
+        env.storage().instance().set(&waitlist_key, &all_waitlists);
+
+        if entry.fee_paid > 0 {
+            env.invoke_contract::<()>(
+                &comp.entry_fee.token,
+                &symbol_short!("transfer"),
+                vec![
+                    &env,
+                    env.current_contract_address().into_val(&env),
+                    artist_address.into_val(&env),
+                    entry.fee_paid.into_val(&env),
+                ],
+            );
+        }
+    }
+
+    /// Withdraw an artist's own submission during the submission window, or during voting if the
+    /// competition allows it, the entry fee is refunded
+    pub fn withdraw_submission(env: Env, id: String, artist_address: Address) {
+        let mut comp: Competition = env.storage().instance().get(&id).unwrap();
+        Self::require_running(&env, &comp);
+        Self::sync_state(&env, &mut comp);
+
+        artist_address.require_auth();
+
+        let now = env.ledger().timestamp();
+        let in_submissions = now >= comp.artist_add_start && now <= comp.artist_add_end;
+        let in_voting = comp.state == CompetitionState::Voting && now <= comp.vote_end;
+        assert!(
+            comp.state == CompetitionState::Submissions || comp.state == CompetitionState::Voting,
+            "Competition not open"
+        );
+        assert!(
+            in_submissions || (in_voting && comp.withdraw_during_voting),
+            "Withdrawal not allowed now"
+        );
+
+        let artist_name = comp
+            .artists
+            .iter()
+            .find(|(addr, _)| *addr == artist_address)
+            .map(|(_, name)| name)
+            .expect("Artist not found in competition");
+
+        Self::drop_artist(&env, &id, &mut comp, &artist_name, true);
+        env.storage().instance().set(&id, &comp);
+
+        env.events()
+            .publish((Symbol::new(&env, "withdrawn"), id), artist_name);
+    }
+
+    /// Add another artwork to an artist's submission during the submission window
+    pub fn add_artwork(
+        env: Env,
+        id: String,
+        artist_name: String,
+        from: Address,
+        artwork_name: String,
+        description: String,
+        img_url: String,
+    ) {
+        let mut comp: Competition = env.storage().instance().get(&id).unwrap();
+
+        Self::require_submitting_artist(&env, &mut comp, &artist_name, &from);
+
+        Self::push_artwork(
+            &env,
+            &mut comp,
+            &artist_name,
+            ArtworkMetadata {
+                artwork_name,
+                description,
+                img_url,
+            },
+        );
+        Self::mark_pending(&env, &mut comp, &artist_name);
+        env.storage().instance().set(&id, &comp);
+    }
+
+    /// Remove one of an artist's artworks during the submission window, the last one can't be removed
+    pub fn remove_artwork(env: Env, id: String, artist_name: String, from: Address, artwork_name: String) {
+        let mut comp: Competition = env.storage().instance().get(&id).unwrap();
+
+        Self::require_submitting_artist(&env, &mut comp, &artist_name, &from);
+
+        let mut artworks = comp.artist_metadata.get(artist_name.clone()).unwrap();
+        let index = artworks
+            .iter()
+            .position(|item| item.artwork_name == artwork_name)
+            .expect("Artwork not found");
+        assert!(artworks.len() > 1, "Cannot remove the only artwork");
+
+        artworks.remove(index as u32);
+        comp.artist_metadata.set(artist_name, artworks);
+        env.storage().instance().set(&id, &comp);
+    }
+
+    /// Replace a rejected submission with a new artwork during the submission window and send it back for review
+    pub fn resubmit_art(
+        env: Env,
+        id: String,
+        artist_name: String,
+        from: Address,
+        artwork_name: String,
+        description: String,
+        img_url: String,
+    ) {
+        let mut comp: Competition = env.storage().instance().get(&id).unwrap();
+
+        Self::require_submitting_artist(&env, &mut comp, &artist_name, &from);
+        assert!(
+            comp.reviews
+                .get(artist_name.clone())
+                .is_some_and(|review| review.status == ReviewStatus::Rejected),
+            "Submission not rejected"
+        );
+
+        comp.artist_metadata.remove(artist_name.clone());
+        Self::push_artwork(
+            &env,
+            &mut comp,
+            &artist_name,
+            ArtworkMetadata {
+                artwork_name,
+                description,
+                img_url,
+            },
+        );
+        Self::mark_pending(&env, &mut comp, &artist_name);
+        env.storage().instance().set(&id, &comp);
+
+        env.events()
+            .publish((Symbol::new(&env, "resubmitted"), id), artist_name);
+    }
+
+    /// Update the name or description of one of an artist's artworks during the submission window,
+    /// selected by its current name
+    pub fn update_submission_metadata(
+        env: Env,
+        id: String,
+        artist_name: String,
+        from: Address,
+        artwork: String,
+        artwork_name: Option<String>,
+        description: Option<String>,
+    ) {
+        let mut comp: Competition = env.storage().instance().get(&id).unwrap();
+
+        // Edits go back to review, so they are only allowed while submissions are open
+        Self::require_submitting_artist(&env, &mut comp, &artist_name, &from);
+
+        // Get current metadata
+        let mut artworks = comp.artist_metadata.get(artist_name.clone()).unwrap();
+        let index = artworks
+            .iter()
+            .position(|item| item.artwork_name == artwork)
+            .expect("Artwork not found") as u32;
+
+        let mut updated_art = artworks.get(index).unwrap();
+        if let Some(name) = artwork_name {
+            updated_art.artwork_name = name;
+        }
+        if let Some(desc) = description {
+            updated_art.description = desc;
+        }
+        Self::check_duplicate_artwork(&artworks, &updated_art, Some(index));
+        artworks.set(index, updated_art);
+
+        Self::mark_pending(&env, &mut comp, &artist_name);
+        comp.artist_metadata.set(artist_name, artworks);
+        env.storage().instance().set(&id, &comp);
+    }
+
+    ///***{Artist Write Fn for Artist Registration}***
+    ///
+    /// Add artist profile info (one per address, unique name)
+    pub fn add_artist_info(
+        env: Env,
+        from: Address,
+        name: String,
+        bio: String,
+        img_url: String,
+        website: String,
+        mediums: Vec<Medium>,
+        blockchains: Vec<Network>,
+    ) {
+        Self::require_not_paused(&env);
+
+        from.require_auth();
+
+        let artist_info_key = Symbol::new(&env, "artist_info");
+
+        // Load all artist info map: Address -> ArtistInfo
+        let mut all_info: Map<Address, ArtistInfo> = env
+            .storage()
+            .instance()
+            .get(&artist_info_key)
+            .unwrap_or(Map::new(&env));
+
+        // Check for exact name matches instead of case-insensitive
+        for (_, info) in all_info.iter() {
+            if info.name == name {
+                panic!("Artist name already exists");
+            }
+        }
+
+        // Save artist info for this address
+        let info = ArtistInfo {
+            registered: true,
+            name,
+            bio,
+            img_url,
+            website,
+            mediums,
+            blockchains,
+            competitions_participated: 0,
+            competitions_won: 0,
+        };
+        all_info.set(from.clone(), info);
+
+        env.storage().instance().set(&artist_info_key, &all_info);
+    }
+
+    /// Update artist profile info (only by the artist, optional fields)
+    pub fn update_artist_info(
+        env: Env,
+        from: Address,
+        name: Option<String>,
+        bio: Option<String>,
+        img_url: Option<String>,
+        website: Option<String>,
+        mediums: Option<Vec<Medium>>,
+        blockchains: Option<Vec<Network>>,
+    ) {
+        Self::require_not_paused(&env);
+
+        from.require_auth();
+
+        let artist_info_key = Symbol::new(&env, "artist_info");
+        let mut all_info: Map<Address, ArtistInfo> = env
+            .storage()
+            .instance()
+            .get(&artist_info_key)
+            .unwrap_or(Map::new(&env));
+
+        // Must exist to update
+        let mut info = all_info.get(from.clone()).expect("Artist info not found");
+
+        // If updating name, check for duplicates (exact match)
+        if let Some(ref new_name) = name {
+            for (addr, other_info) in all_info.iter() {
+                if addr != from && other_info.name == *new_name {
+                    panic!("Artist name already exists");
+                }
+            }
+            info.name = new_name.clone();
+        }
+        if let Some(new_bio) = bio {
+            info.bio = new_bio;
+        }
+        if let Some(new_img) = img_url {
+            info.img_url = new_img;
+        }
+        if let Some(new_website) = website {
+            info.website = new_website;
+        }
+        if let Some(new_mediums) = mediums {
+            info.mediums = new_mediums;
+        }
+        if let Some(new_blockchains) = blockchains {
+            info.blockchains = new_blockchains;
+        }
+
+        // registered, competitions_participated, competitions_won remain unchanged
+        all_info.set(from.clone(), info);
+        env.storage().instance().set(&artist_info_key, &all_info);
+    }
+
+    /// ***{Read Functions}***
+    ///
+    /// Get all active competitions based on current timestamp, nothing is written
+    pub fn get_active_competitions(env: Env) -> Vec<CompetitionStatus> {
+        let now = env.ledger().timestamp();
+        let mut active_competitions = Vec::new(&env);
+        let competition_list_key = Symbol::new(&env, "comp_list");
+        let competition_ids: Vec<String> = env
+            .storage()
+            .instance()
+            .get(&competition_list_key)
+            .unwrap_or(Vec::new(&env));
+
+        for id in competition_ids.iter() {
+            if let Some(mut comp) = env.storage().instance().get::<String, Competition>(&id) {
+                if comp.state == CompetitionState::Archived {
+                    continue;
+                }
+
+                // Show competitions past voting as they would be once finalized
+                comp = Self::finalized_view(&env, &comp);
+
+                let is_submission_active =
+                    now >= comp.artist_add_start && now <= comp.artist_add_end;
+                let is_voting_active = now >= comp.vote_start && now <= comp.vote_end;
+                let is_finalized = comp.state == CompetitionState::Finalized
+                    || comp.state == CompetitionState::Paid;
+
+                if is_submission_active || is_voting_active || (now <= comp.vote_end + 86400) {
+                    active_competitions.push_back(CompetitionStatus {
+                        id: id.clone(),
+                        competition: comp,
+                        is_submission_active,
+                        is_voting_active,
+                        is_finalized,
+                    });
+                }
+            }
+        }
+
+        active_competitions
+    }
+
+    /// Get the competition details
+    pub fn get_competition(env: Env, id: String) -> Competition {
+        let comp: Competition = env.storage().instance().get(&id).unwrap();
+        Self::finalized_view(&env, &comp)
+    }
+
+    /// View all submitted artists and their metadata for a competition, approved ones only when moderated
+    pub fn get_comp_artists(env: Env, id: String) -> Vec<(String, ArtworkMetadata)> {
+        let comp: Competition = env.storage().instance().get(&id).unwrap();
+
+        let mut result = Vec::new(&env);
+        for (artist, artworks) in comp.artist_metadata.iter() {
+            if !Self::is_listed(&comp, &artist) {
+                continue;
+            }
+            for art in artworks.iter() {
+                result.push_back((artist.clone(), art));
+            }
+        }
+
+        result
+    }
+
+    /// View the submissions of a moderated competition awaiting review
+    pub fn get_pending_submissions(env: Env, id: String) -> Vec<(String, ArtworkMetadata)> {
+        let comp: Competition = env.storage().instance().get(&id).unwrap();
+
+        let mut result = Vec::new(&env);
+        for (artist, review) in comp.reviews.iter() {
+            if review.status != ReviewStatus::Pending {
+                continue;
+            }
+            for art in comp.artist_metadata.get(artist.clone()).unwrap().iter() {
+                result.push_back((artist.clone(), art));
+            }
+        }
+
+        result
+    }
+
+    /// Get the total pot for a competition in the competition token
+    pub fn get_pot(env: Env, id: String) -> i128 {
+        let comp: Competition = env.storage().instance().get(&id).unwrap();
+        comp.pots.get(comp.token).unwrap_or(0)
+    }
+
+    /// Get the pot of every token funding a competition
+    pub fn get_pots(env: Env, id: String) -> Map<Address, i128> {
+        let comp: Competition = env.storage().instance().get(&id).unwrap();
+        comp.pots
+    }
+
+    /// Get the tokens admins approved for funding pots besides each competition's own token
+    pub fn get_pot_tokens(env: Env) -> Vec<Address> {
+        env.storage()
+            .instance()
+            .get(&Symbol::new(&env, "pot_tokens"))
+            .unwrap_or(Vec::new(&env))
+    }
+
+    /// Get the prizes allocated by `pay_winners` that have not been transferred yet
+    pub fn get_pending_payouts(env: Env, id: String) -> Vec<PendingPayout> {
+        let pending_key = Symbol::new(&env, "pending_payouts");
+        let all_pending: Map<String, Vec<PendingPayout>> = env
+            .storage()
+            .instance()
+            .get(&pending_key)
+            .unwrap_or(Map::new(&env));
+
+        all_pending.get(id).unwrap_or(Vec::new(&env))
+    }
+
+    /// Get the record of every prize allocated by `pay_winners` and what happened to it
+    pub fn get_payouts(env: Env, id: String) -> Vec<PayoutRecord> {
+        let all_records: Map<String, Vec<PayoutRecord>> = env
+            .storage()
+            .instance()
+            .get(&Symbol::new(&env, "payouts"))
+            .unwrap_or(Map::new(&env));
+        all_records.get(id).unwrap_or(Vec::new(&env))
+    }
+
+    /// Get each winner's vesting prizes with the vested, claimed and remaining amounts
+    pub fn get_vesting(env: Env, id: String) -> Vec<VestingStatus> {
+        let comp: Competition = env.storage().instance().get(&id).unwrap();
+        let all_positions: Map<String, Vec<VestingPosition>> = env
+            .storage()
+            .instance()
+            .get(&Symbol::new(&env, "vesting"))
+            .unwrap_or(Map::new(&env));
+
+        let now = env.ledger().timestamp();
+        let mut result = Vec::new(&env);
+        for position in all_positions.get(id).unwrap_or(Vec::new(&env)).iter() {
+            let vested = Self::vested_amount(&comp.payout_schedule, &position, now);
+            result.push_back(VestingStatus {
+                artist: position.artist,
+                address: position.address,
+                token: position.token,
+                total: position.total,
+                vested,
+                claimed: position.claimed,
+                remaining: position.total - position.claimed,
+            });
+        }
+        result
+    }
+
+    /// Get the reward paid to whoever finalizes a competition
+    pub fn get_keeper_reward(env: Env) -> i128 {
+        env.storage()
+            .instance()
+            .get(&Symbol::new(&env, "keeper_reward"))
+            .unwrap_or(0)
+    }
+
+    /// Get the claim window in seconds applied when prizes are allocated
+    pub fn get_claim_window(env: Env) -> u64 {
+        env.storage()
+            .instance()
+            .get(&Symbol::new(&env, "claim_window"))
+            .unwrap_or(0)
+    }
+
+    /// Get how much a funder has contributed to a competition's pot in a token
+    pub fn get_contribution(env: Env, id: String, funder: Address, token: Address) -> i128 {
+        for sponsor in Self::get_sponsors(env, id).iter() {
+            if sponsor.funder == funder && sponsor.token == token {
+                return sponsor.amount;
+            }
+        }
+        0
+    }
+
+    /// Get a competition series template with the ids of its editions
+    pub fn get_series(env: Env, id: String) -> CompetitionSeries {
+        let all_series: Map<String, CompetitionSeries> = env
+            .storage()
+            .instance()
+            .get(&Symbol::new(&env, "series"))
+            .unwrap_or(Map::new(&env));
+        all_series.get(id).expect("Series not found")
+    }
+
+    /// Get all competition series
+    pub fn get_all_series(env: Env) -> Vec<CompetitionSeries> {
+        let all_series: Map<String, CompetitionSeries> = env
+            .storage()
+            .instance()
+            .get(&Symbol::new(&env, "series"))
+            .unwrap_or(Map::new(&env));
+        all_series.values()
+    }
+
+    /// Get every edition of a series from first to latest
+    pub fn get_series_editions(env: Env, id: String) -> Vec<Competition> {
+        let mut editions = Vec::new(&env);
+        for edition_id in Self::get_series(env.clone(), id).editions.iter() {
+            if let Some(comp) = env.storage().instance().get::<String, Competition>(&edition_id) {
+                editions.push_back(Self::finalized_view(&env, &comp));
+            }
+        }
+        editions
+    }
+
+    /// Get the submissions waiting for a free slot in a competition, first in line first
+    pub fn get_waitlist(env: Env, id: String) -> Vec<WaitlistEntry> {
+        let all_waitlists: Map<String, Vec<WaitlistEntry>> = env
+            .storage()
+            .instance()
+            .get(&Symbol::new(&env, "waitlist"))
+            .unwrap_or(Map::new(&env));
+        all_waitlists.get(id).unwrap_or(Vec::new(&env))
+    }
+
+    /// Get the reports filed in a competition, resolved ones included
+    pub fn get_reports(env: Env, id: String) -> Vec<Report> {
+        let all_reports: Map<String, Vec<Report>> = env
+            .storage()
+            .instance()
+            .get(&Symbol::new(&env, "reports"))
+            .unwrap_or(Map::new(&env));
+        all_reports.get(id).unwrap_or(Vec::new(&env))
+    }
+
+    /// Count the open reports per submission of a competition
+    pub fn get_report_counts(env: Env, id: String) -> Map<String, u32> {
+        let mut counts = Map::new(&env);
+        for report in Self::get_reports(env.clone(), id).iter() {
+            if !report.resolved {
+                let count = counts.get(report.artist.clone()).unwrap_or(0);
+                counts.set(report.artist, count + 1);
+            }
+        }
+        counts
+    }
+
+    /// Get the sponsors of a competition, one entry per token funded, grouped by token in the order the tokens
+    /// were first funded and sorted by contribution from largest to smallest within each token
+    pub fn get_sponsors(env: Env, id: String) -> Vec<Sponsor> {
+        let all_sponsors: Map<String, Vec<Sponsor>> = env
+            .storage()
+            .instance()
+            .get(&Symbol::new(&env, "sponsors"))
+            .unwrap_or(Map::new(&env));
+        let mut sponsors = all_sponsors.get(id).unwrap_or(Vec::new(&env));
+
+        // Amounts of different tokens are not comparable, so only compare within a token
+        let mut tokens: Vec<Address> = Vec::new(&env);
+        for sponsor in sponsors.iter() {
+            if !tokens.contains(&sponsor.token) {
+                tokens.push_back(sponsor.token);
+            }
+        }
+
+        // Sort by token, then by amount (descending) - manual bubble sort
+        let len = sponsors.len();
+        for i in 0..len {
+            for j in 0..(len - 1 - i) {
+                let current = sponsors.get(j).unwrap();
+                let next = sponsors.get(j + 1).unwrap();
+                let current_token = tokens.first_index_of(&current.token).unwrap();
+                let next_token = tokens.first_index_of(&next.token).unwrap();
+                if current_token > next_token
+                    || (current_token == next_token && current.amount < next.amount)
+                {
+                    sponsors.set(j, next);
+                    sponsors.set(j + 1, current);
+                }
+            }
+        }
+
+        sponsors
+    }
+
+    /// Get the entry fees paid by the artists of a competition
+    pub fn get_entry_fees(env: Env, id: String) -> Vec<EntryFeePayment> {
+        let all_entry_fees: Map<String, Vec<EntryFeePayment>> = env
+            .storage()
+            .instance()
+            .get(&Symbol::new(&env, "entry_fees"))
+            .unwrap_or(Map::new(&env));
+        all_entry_fees.get(id).unwrap_or(Vec::new(&env))
+    }
+
+    /// Get the voter reward pool set aside by `pay_winners`
+    pub fn get_voter_reward(env: Env, id: String) -> Option<VoterRewardPool> {
+        let all_pools: Map<String, VoterRewardPool> = env
+            .storage()
+            .instance()
+            .get(&Symbol::new(&env, "voter_rwd"))
+            .unwrap_or(Map::new(&env));
+        all_pools.get(id)
+    }
+
+    /// Get the platform fee in basis points and when it is charged
+    pub fn get_platform_fee(env: Env) -> (u32, FeeMode) {
+        let fee_bps: u32 = env
+            .storage()
+            .instance()
+            .get(&Symbol::new(&env, "fee_bps"))
+            .unwrap_or(0);
+        let fee_mode: FeeMode = env
+            .storage()
+            .instance()
+            .get(&Symbol::new(&env, "fee_mode"))
+            .unwrap_or(FeeMode::OnPayout);
+        (fee_bps, fee_mode)
+    }
+
+    /// Get the treasury address that receives platform fees
+    pub fn get_treasury(env: Env) -> Option<Address> {
+        env.storage()
+            .instance()
+            .get(&Symbol::new(&env, "treasury"))
+    }
+
+    /// Get the platform fees accrued for a token and not yet withdrawn to the treasury
+    pub fn get_treasury_balance(env: Env, token: Address) -> i128 {
+        let balances: Map<Address, i128> = env
+            .storage()
+            .instance()
+            .get(&Symbol::new(&env, "treasury_bal"))
+            .unwrap_or(Map::new(&env));
+        balances.get(token).unwrap_or(0)
+    }
+
+    /// Get the minimum token amount required to vote in a competition
+    pub fn get_min_vote_tokens(env: Env, id: String) -> i128 {
+        let comp: Competition = env.storage().instance().get(&id).unwrap();
+        comp.min_vote_tokens
+    }
+
+    /// Get all contestants ranked by votes from winner to last place
+    ///
+    /// Returns the ranking recorded at finalization, before that ties the policy cannot
+    /// resolve yet share a rank.
+    pub fn get_winner(env: Env, id: String) -> Vec<ArtistRanking> {
+        let comp: Competition = env.storage().instance().get(&id).unwrap();
+
+        if !comp.ranking.is_empty() {
+            return comp.ranking;
+        }
+        Self::rank_artists(&env, &comp, false)
+    }
+
+    /// Get all artists and their info stored on the contract
+    pub fn get_artists(env: Env) -> Vec<(Address, ArtistInfo)> {
+        let artist_info_key = Symbol::new(&env, "artist_info");
+        let all_info: Map<Address, ArtistInfo> = env
+            .storage()
+            .instance()
+            .get(&artist_info_key)
+            .unwrap_or(Map::new(&env));
+        let mut result = Vec::new(&env);
+        for (addr, info) in all_info.iter() {
+            result.push_back((addr, info));
+        }
+        result
+    }
+
+    /// Get artist info for a specific address
+    pub fn get_artist_info(env: Env, address: Address) -> Option<ArtistInfo> {
+        let artist_info_key = Symbol::new(&env, "artist_info");
+        let all_info: Map<Address, ArtistInfo> = env
+            .storage()
+            .instance()
+            .get(&artist_info_key)
+            .unwrap_or(Map::new(&env));
+        all_info.get(address)
+    }
+
+    /// Get the votes cast for each of an artist's artworks, votes for the artist alone are not included
+    pub fn get_artwork_votes(env: Env, id: String, artist: String) -> Map<String, u64> {
+        let comp: Competition = env.storage().instance().get(&id).unwrap();
+        comp.artwork_votes.get(artist).unwrap_or(Map::new(&env))
+    }
+
+    /// Get vote history for a competition
+    pub fn get_vote_history(env: Env, id: String) -> Vec<VoteHistory> {
+        let vote_history_key = Symbol::new(&env, "vote_hist");
+        let all_vote_history: Map<String, Vec<VoteHistory>> = env
+            .storage()
+            .instance()
+            .get(&vote_history_key)
+            .unwrap_or(Map::new(&env));
+
+        all_vote_history.get(id).unwrap_or(Vec::new(&env))
+    }
+
+    /// check if an wallet has registered artist info
+    pub fn has_registered(env: Env, address: Address) -> bool {
+        let artist_info_key = Symbol::new(&env, "artist_info");
+        let all_info: Map<Address, ArtistInfo> = env
+            .storage()
+            .instance()
+            .get(&artist_info_key)
+            .unwrap_or(Map::new(&env));
+        all_info.contains_key(address)
+    }
+
+    /// Check if a voter voted
+    pub fn has_voted(env: Env, id: String, voter: Address) -> Option<String> {
+        let comp: Competition = env.storage().instance().get(&id).unwrap();
+        comp.vote_log.get(voter)
+    }
+
+    /// Check if a user can vote
+    pub fn check_voting_eligibility(env: Env, id: String, voter: Address) -> VotingEligibility {
+        let comp: Competition = env.storage().instance().get(&id).unwrap();
+        let now = env.ledger().timestamp();
+
+        let paused = comp.paused_at != 0 || Self::is_paused(env.clone());
+        let voting_active = now >= comp.vote_start
+            && now <= comp.vote_end
+            && Self::current_state(&env, &comp) == CompetitionState::Voting
+            && !paused;
+
+        // Get voter's token balance in base units
+        let current_balance: i128 = env.invoke_contract(
+            &comp.token,
+            &Symbol::new(&env, "balance"),
+            vec![&env, voter.into_val(&env)],
+        );
+
+        let min_required = comp.min_vote_tokens;
+        let has_voted = comp.vote_log.contains_key(voter.clone());
+        let can_vote = voting_active && current_balance >= min_required && !has_voted;
+
+        VotingEligibility {
+            can_vote,
+            has_voted,
+            current_balance,
+            min_required,
+            voting_active,
+        }
+    }
+    /// Check if the contract is globally paused
+    pub fn is_paused(env: Env) -> bool {
+        env.storage()
+            .instance()
+            .get::<Symbol, u64>(&Symbol::new(&env, "paused_at"))
+            .unwrap_or(0)
+            != 0
+    }
+    /// Get the current contract version
+    pub fn version(env: Env) -> String {
+        String::from_str(&env, "2.0.0")
+    }
+    /// Get the current admins of the contract
+    pub fn get_admins(env: Env) -> (Option<Address>, Option<Address>) {
+        let admin1 = env
+            .storage()
+            .instance()
+            .get::<Symbol, Address>(&Symbol::new(&env, "admin1"));
+        let admin2 = env
+            .storage()
+            .instance()
+            .get::<Symbol, Address>(&Symbol::new(&env, "admin2"));
+        (admin1, admin2)
+    }
+    // Internal function to validate, store and list a new competition, published or as a draft
+    fn store_new_competition(
+        env: &Env,
+        id: String,
+        id_description: String,
+        artist_add_start: u64,
+        artist_add_end: u64,
+        vote_start: u64,
+        vote_end: u64,
+        token: Address,
+        min_vote_tokens: i128,
+        publish: bool,
+    ) -> Competition {
+        let version: u32 = env
+            .storage()
+            .instance()
+            .get(&Symbol::new(env, "storage_ver"))
+            .unwrap_or(1);
+        assert!(version == STORAGE_VERSION, "Storage not migrated");
+
+        assert!(
+            artist_add_start < artist_add_end,
+            "Invalid submission window"
+        );
+        assert!(min_vote_tokens >= 0, "Invalid minimum vote tokens");
+        assert!(
+            vote_start > artist_add_end,
+            "Vote start must be after submission end"
+        );
+        assert!(vote_end > vote_start, "Invalid voting window");
+
+        // Check if competition ID already exists
+        assert!(
+            env.storage()
+                .instance()
+                .get::<String, Competition>(&id)
+                .is_none(),
+            "Competition ID already exists"
+        );
+
+        let mut comp = Self::new_competition(
+            env,
+            id.clone(),
+            id_description,
+            artist_add_start,
+            artist_add_end,
+            vote_start,
+            vote_end,
+            token,
+            min_vote_tokens,
+        );
+        if publish {
+            Self::set_state(env, &mut comp, CompetitionState::Scheduled);
+            Self::sync_state(env, &mut comp);
+        }
+        env.storage().instance().set(&id, &comp);
+
+        // Add competition ID to the list
+        let competition_list_key = Symbol::new(env, "comp_list");
+        let mut competition_ids: Vec<String> = env
+            .storage()
+            .instance()
+            .get(&competition_list_key)
+            .unwrap_or(Vec::new(env));
+        competition_ids.push_back(id.clone());
+        env.storage()
+            .instance()
+            .set(&competition_list_key, &competition_ids);
+
+        comp
+    }
+    // Internal function to build a draft competition with the default settings
+    fn new_competition(
+        env: &Env,
+        id: String,
+        id_description: String,
+        artist_add_start: u64,
+        artist_add_end: u64,
+        vote_start: u64,
+        vote_end: u64,
+        token: Address,
+        min_vote_tokens: i128,
+    ) -> Competition {
+        // Snapshot the platform fee so later fee changes don't affect this competition
+        let fee_bps: u32 = env
+            .storage()
+            .instance()
+            .get(&Symbol::new(env, "fee_bps"))
+            .unwrap_or(0);
+        let fee_mode: FeeMode = env
+            .storage()
+            .instance()
+            .get(&Symbol::new(env, "fee_mode"))
+            .unwrap_or(FeeMode::OnPayout);
+
+        Competition {
+            id,
+            id_description,
+            artist_add_start,
+            artist_add_end,
+            vote_start,
+            vote_end,
+            token: token.clone(),
+            min_vote_tokens,
+            artists: Vec::new(env),
+            votes: Map::new(env),
+            vote_log: Map::new(env),
+            state: CompetitionState::Draft,
+            winner: None,
+            pots: Map::new(env),
+            artist_metadata: Map::new(env),
+            share_ratio: Vec::from_array(env, [50u32, 30u32, 20u32]), // Default share ratio
+            claim_deadline: 0,
+            fee_bps,
+            fee_mode,
+            voter_reward_pct: 0,
+            voter_reward_mode: VoterRewardMode::WinnerBackers,
+            fees_held: Map::new(env),
+            keeper_paid: 0,
+            entry_fee: EntryFee {
+                token,
+                amount: 0,
+                pot_pct: 0,
+            },
+            payout_schedule: PayoutSchedule {
+                cliff: 0,
+                duration: 0,
+            },
+            tie_break: TieBreak::SplitEvenly,
+            jury_order: Vec::new(env),
+            ranking: Vec::new(env),
+            successor: None,
+            paused_at: 0,
+            max_artists: 0,
+            max_artworks_per_artist: 0,
+            artwork_votes: Map::new(env),
+            withdraw_during_voting: false,
+            require_profile: false,
+            moderated: false,
+            reviews: Map::new(env),
+            report_policy: ReportPolicy {
+                max_per_address: 0,
+                deposit: 0,
+                threshold: 0,
+            },
+            hidden: Vec::new(env),
+            disqualified: Map::new(env),
+        }
+    }
+    // Internal function to record a wallet's vote for an artist and optionally one of their artworks
+    fn cast_vote(env: &Env, id: String, from: Address, artist: String, artwork: Option<String>) {
+        let mut comp: Competition = env.storage().instance().get(&id).unwrap();
+        Self::require_running(env, &comp);
+        Self::sync_state(env, &mut comp);
+
+        // Authenticate first before any external calls
         from.require_auth();
 
-        let artist_info_key = Symbol::new(&env, "artist_info");
+        // Use the voting eligibility check
+        let eligibility = Self::check_voting_eligibility(env.clone(), id.clone(), from.clone());
+        assert!(eligibility.can_vote, "Not eligible to vote");
+        assert!(!eligibility.has_voted, "Already voted");
+
+        // Verify artist exists
+        assert!(
+            comp.artists.iter().any(|(_, name)| name == artist),
+            "Invalid artist"
+        );
+        assert!(Self::is_listed(&comp, &artist), "Submission not approved");
+
+        // Tally the artwork as well when the vote targets one
+        if let Some(artwork_name) = artwork.clone() {
+            let artworks = comp.artist_metadata.get(artist.clone()).unwrap();
+            assert!(
+                artworks.iter().any(|item| item.artwork_name == artwork_name),
+                "Invalid artwork"
+            );
+            let mut tally = comp
+                .artwork_votes
+                .get(artist.clone())
+                .unwrap_or(Map::new(env));
+            let current_votes = tally.get(artwork_name.clone()).unwrap_or(0);
+            tally.set(artwork_name, current_votes + 1);
+            comp.artwork_votes.set(artist.clone(), tally);
+        }
+
+        // Update vote_log before any external calls
+        comp.vote_log.set(from.clone(), artist.clone());
+
+        // Record the vote
+        let current_votes = comp.votes.get(artist.clone()).unwrap_or(0);
+        comp.votes.set(artist.clone(), current_votes + 1);
+
+        // Store vote history using the competition's vote log directly
+        // We'll store vote history within the competition structure by extending it
+        let vote_record = VoteHistory {
+            voter: from,
+            artist,
+            artwork,
+            timestamp: env.ledger().timestamp(),
+        };
+
+        // Get existing vote history for this competition
+        let vote_history_key = Symbol::new(env, "vote_hist");
+        let mut all_vote_history: Map<String, Vec<VoteHistory>> = env
+            .storage()
+            .instance()
+            .get(&vote_history_key)
+            .unwrap_or(Map::new(env));
+
+        let mut comp_history = all_vote_history.get(id.clone()).unwrap_or(Vec::new(env));
+        comp_history.push_back(vote_record);
+        all_vote_history.set(id.clone(), comp_history);
+
+        env.storage()
+            .instance()
+            .set(&vote_history_key, &all_vote_history);
+        env.storage().instance().set(&id, &comp);
+    }
+    // Internal function to build an edition id from the series id and a number padded to 3 digits, e.g. "weekly-042"
+    fn edition_id(env: &Env, series_id: &String, edition: u32) -> String {
+        let mut buf = [0u8; 64];
+        let len = series_id.len() as usize;
+        series_id.copy_into_slice(&mut buf[..len]);
+        buf[len] = b'-';
+
+        let mut digits = [0u8; 10];
+        let mut count = 0usize;
+        let mut n = edition;
+        while n > 0 || count < 3 {
+            digits[count] = b'0' + (n % 10) as u8;
+            n /= 10;
+            count += 1;
+        }
+        for k in 0..count {
+            buf[len + 1 + k] = digits[count - 1 - k];
+        }
+        String::from_bytes(env, &buf[..len + 1 + count])
+    }
+    // Internal function to adjust the participation and win counters of an artist's profile, if they have one.
+    // Returns whether a profile was adjusted.
+    fn adjust_artist_stats(env: &Env, address: &Address, participated: i32, won: i32) -> bool {
+        let artist_info_key = Symbol::new(env, "artist_info");
         let mut all_info: Map<Address, ArtistInfo> = env
             .storage()
             .instance()
             .get(&artist_info_key)
-            .unwrap_or(Map::new(&env));
+            .unwrap_or(Map::new(env));
+        if let Some(mut info) = all_info.get(address.clone()) {
+            info.competitions_participated = info
+                .competitions_participated
+                .saturating_add_signed(participated);
+            info.competitions_won = info.competitions_won.saturating_add_signed(won);
+            all_info.set(address.clone(), info);
+            env.storage().instance().set(&artist_info_key, &all_info);
+            return true;
+        }
+        false
+    }
+    // Internal function to get the profiles whose counters a competition incremented
+    fn stats_credit(env: &Env, id: &String) -> StatsCredit {
+        let all_credit: Map<String, StatsCredit> = env
+            .storage()
+            .instance()
+            .get(&Symbol::new(env, "stats_credit"))
+            .unwrap_or(Map::new(env));
+        all_credit.get(id.clone()).unwrap_or(StatsCredit {
+            participated: Vec::new(env),
+            won: Vec::new(env),
+        })
+    }
+    // Internal function to save the profiles whose counters a competition incremented
+    fn save_stats_credit(env: &Env, id: &String, credit: StatsCredit) {
+        let credit_key = Symbol::new(env, "stats_credit");
+        let mut all_credit: Map<String, StatsCredit> = env
+            .storage()
+            .instance()
+            .get(&credit_key)
+            .unwrap_or(Map::new(env));
+        all_credit.set(id.clone(), credit);
+        env.storage().instance().set(&credit_key, &all_credit);
+    }
+    // Internal function to determine the winner without prize distribution
+    fn internal_finalize(env: &Env, comp: &mut Competition) {
+        // Early check: if no artists, mark as finalized with no winner
+        if comp.artists.is_empty() {
+            comp.winner = None;
+            Self::set_state(env, comp, CompetitionState::Finalized);
+            return;
+        }
 
-        // Must exist to update
-        let mut info = all_info.get(from.clone()).expect("Artist info not found");
+        // Rank the artists, drawing lots now if the competition breaks ties at random
+        if !Self::settle_ranking(env, comp, true) {
+            return;
+        }
 
-        // If updating name, check for duplicates (exact match)
-        if let Some(ref new_name) = name {
-            for (addr, other_info) in all_info.iter() {
-                if addr != from && other_info.name == *new_name {
-                    assert!(false, "Artist name already exists");
+        // Increment competitions_won for every winning artist, tied winners when splitting evenly
+        let mut credit = Self::stats_credit(env, &comp.id);
+        for entry in comp.ranking.iter() {
+            if !entry.is_winner {
+                continue;
+            }
+            for (addr, name) in comp.artists.iter() {
+                if name == entry.artist {
+                    if Self::adjust_artist_stats(env, &addr, 0, 1) {
+                        credit.won.push_back(addr);
+                    }
+                    break;
                 }
             }
-            info.name = new_name.clone();
-        }
-        if let Some(new_bio) = bio {
-            info.bio = new_bio;
         }
-        if let Some(new_img) = img_url {
-            info.img_url = new_img;
+        Self::save_stats_credit(env, &comp.id, credit);
+
+        Self::set_state(env, comp, CompetitionState::Finalized);
+    }
+    // Internal function to record the ranking and winner of a competition whose voting ended.
+    // Returns false and leaves the competition as it was while a tie still awaits the jury or the draw.
+    fn settle_ranking(env: &Env, comp: &mut Competition, draw: bool) -> bool {
+        let ranking = Self::rank_artists(env, comp, draw);
+
+        let unresolved = ranking
+            .iter()
+            .enumerate()
+            .any(|(i, entry)| entry.votes > 0 && entry.rank != i as u32 + 1);
+        let waiting = comp.tie_break == TieBreak::JuryDecides
+            || (comp.tie_break == TieBreak::Random && !draw);
+        if waiting && unresolved {
+            return false;
         }
-        if let Some(new_website) = website {
-            info.website = new_website;
+
+        // Set winner (top artist with votes > 0) - handle case where no votes were cast
+        comp.winner = match ranking.get(0) {
+            Some(top) if top.votes > 0 => Some(top.artist),
+            _ => None,
+        };
+        comp.ranking = ranking;
+        true
+    }
+    // Internal function to show a competition as it would be if finalized now, without writing anything
+    fn finalized_view(env: &Env, comp: &Competition) -> Competition {
+        let mut view = comp.clone();
+        view.state = Self::current_state(env, comp);
+        if view.state == CompetitionState::Tallying && Self::settle_ranking(env, &mut view, false) {
+            view.state = CompetitionState::Finalized;
         }
-        if let Some(new_mediums) = mediums {
-            info.mediums = new_mediums;
+        view
+    }
+    // Internal function to rank artists by votes, ordering tied artists by the tie-break policy.
+    // Ties the policy cannot resolve share a rank, and random draws only happen when `draw` is set.
+    fn rank_artists(env: &Env, comp: &Competition, draw: bool) -> Vec<ArtistRanking> {
+        // Collect all artists with their votes in submission order
+        let mut artist_votes = Vec::new(env);
+        for (_, artist_name) in comp.artists.iter() {
+            if !Self::is_ranked(comp, &artist_name) {
+                continue;
+            }
+            let votes = comp.votes.get(artist_name.clone()).unwrap_or(0);
+            artist_votes.push_back((artist_name.clone(), votes));
         }
-        if let Some(new_blockchains) = blockchains {
-            info.blockchains = new_blockchains;
+
+        // Sort by votes (descending) - manual bubble sort, stable so ties keep submission order
+        let len = artist_votes.len();
+        for i in 0..len {
+            for j in 0..(len - 1 - i) {
+                let current_votes = artist_votes.get(j).unwrap().1;
+                let next_votes = artist_votes.get(j + 1).unwrap().1;
+                if current_votes < next_votes {
+                    let temp = artist_votes.get(j).unwrap();
+                    let next = artist_votes.get(j + 1).unwrap();
+                    artist_votes.set(j, next);
+                    artist_votes.set(j + 1, temp);
+                }
+            }
         }
 
-        // registered, competitions_participated, competitions_won remain unchanged
-        all_info.set(from.clone(), info);
-        env.storage().instance().set(&artist_info_key, &all_info);
+        let mut rankings = Vec::new(env);
+        let mut start = 0u32;
+        while start < len {
+            // Find the artists tied with this one
+            let votes = artist_votes.get(start).unwrap().1;
+            let mut end = start + 1;
+            while end < len && artist_votes.get(end).unwrap().1 == votes {
+                end += 1;
+            }
+            let mut group = artist_votes.slice(start..end);
+
+            let resolved = group.len() == 1
+                || match comp.tie_break {
+                    TieBreak::SplitEvenly => false,
+                    TieBreak::EarliestSubmission => true,
+                    TieBreak::JuryDecides => {
+                        let mut ordered = Vec::new(env);
+                        for name in comp.jury_order.iter() {
+                            for entry in group.iter() {
+                                if entry.0 == name {
+                                    ordered.push_back(entry);
+                                }
+                            }
+                        }
+                        let listed = ordered.len() == group.len();
+                        if listed {
+                            group = ordered;
+                        }
+                        listed
+                    }
+                    TieBreak::Random => {
+                        if draw {
+                            env.prng().shuffle(&mut group);
+                        }
+                        draw
+                    }
+                };
+
+            for (offset, (artist, votes)) in group.iter().enumerate() {
+                let rank = if resolved { start + offset as u32 + 1 } else { start + 1 };
+                rankings.push_back(ArtistRanking {
+                    artist,
+                    votes,
+                    rank,
+                    is_winner: rank == 1 && votes > 0,
+                });
+            }
+            start = end;
+        }
+        rankings
     }
+    // Internal function to check whether an artist is among a finalized competition's winners
+    fn is_winner(comp: &Competition, artist: &String) -> bool {
+        comp.ranking
+            .iter()
+            .any(|entry| entry.is_winner && entry.artist == *artist)
+    }
+    // Internal function to move a competition to a new state, rejecting transitions the lifecycle does not allow
+    fn set_state(env: &Env, comp: &mut Competition, to: CompetitionState) {
+        use CompetitionState::*;
+        let from = comp.state;
+        let allowed = matches!(
+            (from, to),
+            (Draft, Scheduled)
+                | (Scheduled, Submissions)
+                | (Submissions, Voting)
+                | (Voting, Tallying)
+                | (Tallying, Finalized)
+                | (Finalized, Paid)
+                | (Draft | Scheduled | Submissions | Voting | Tallying | Finalized, Cancelled)
+                | (Draft | Finalized | Paid | Cancelled, Archived)
+        );
+        assert!(allowed, "Invalid state transition");
 
-    /// ***{Read Functions}***
-    ///
-    /// Get all active competitions based on current timestamp
-    pub fn get_active_competitions(env: Env) -> Vec<CompetitionStatus> {
+        comp.state = to;
+        env.events().publish(
+            (Symbol::new(env, "state_changed"), comp.id.clone()),
+            (from, to),
+        );
+    }
+    // Internal function to find the phase a published competition has reached by time, if it moved on
+    fn next_phase(env: &Env, comp: &Competition) -> Option<CompetitionState> {
         let now = env.ledger().timestamp();
-        let mut active_competitions = Vec::new(&env);
-        let competition_list_key = Symbol::new(&env, "comp_list");
-        let competition_ids: Vec<String> = env
+        match comp.state {
+            CompetitionState::Scheduled if now >= comp.artist_add_start => {
+                Some(CompetitionState::Submissions)
+            }
+            // Submissions covers the submission window and the wait until voting opens
+            CompetitionState::Submissions if now >= comp.vote_start => Some(CompetitionState::Voting),
+            CompetitionState::Voting if now > comp.vote_end => Some(CompetitionState::Tallying),
+            _ => None,
+        }
+    }
+    // Internal function to advance a competition through the timed phases, the caller saves it
+    fn sync_state(env: &Env, comp: &mut Competition) {
+        while let Some(next) = Self::next_phase(env, comp) {
+            Self::set_state(env, comp, next);
+        }
+    }
+    // Internal function to get the state a competition is in by time without recording the transitions
+    fn current_state(env: &Env, comp: &Competition) -> CompetitionState {
+        let mut view = comp.clone();
+        while let Some(next) = Self::next_phase(env, &view) {
+            view.state = next;
+        }
+        view.state
+    }
+    // Internal function to check that a competition has not been finalized, cancelled or archived
+    fn is_open(comp: &Competition) -> bool {
+        matches!(
+            comp.state,
+            CompetitionState::Draft
+                | CompetitionState::Scheduled
+                | CompetitionState::Submissions
+                | CompetitionState::Voting
+                | CompetitionState::Tallying
+        )
+    }
+    // Internal function to check that a competition's pot can still grow
+    fn can_take_funds(comp: &Competition) -> bool {
+        Self::is_open(comp) || comp.state == CompetitionState::Finalized
+    }
+    // Internal function to block user writes while the contract is globally paused
+    fn require_not_paused(env: &Env) {
+        assert!(!Self::is_paused(env.clone()), "Contract paused");
+    }
+    // Internal function to block writes to a competition while it or the contract is paused
+    fn require_running(env: &Env, comp: &Competition) {
+        Self::require_not_paused(env);
+        assert!(comp.paused_at == 0, "Competition paused");
+    }
+    // Internal function to push back the phase boundaries still ahead at `since` by `delay` seconds
+    fn extend_schedule(comp: &mut Competition, since: u64, delay: u64) {
+        if comp.artist_add_start > since {
+            comp.artist_add_start += delay;
+        }
+        if comp.artist_add_end >= since {
+            comp.artist_add_end += delay;
+        }
+        if comp.vote_start > since {
+            comp.vote_start += delay;
+        }
+        if comp.vote_end >= since {
+            comp.vote_end += delay;
+        }
+        if comp.claim_deadline >= since {
+            comp.claim_deadline += delay;
+        }
+    }
+    // Internal function to check that `from` is one of the two admins and has signed
+    fn require_admin(env: &Env, from: &Address, message: &str) {
+        let admin1: Address = env
             .storage()
             .instance()
-            .get(&competition_list_key)
-            .unwrap_or(Vec::new(&env));
+            .get(&Symbol::new(env, "admin1"))
+            .unwrap();
+        let admin2: Address = env
+            .storage()
+            .instance()
+            .get(&Symbol::new(env, "admin2"))
+            .unwrap();
+        assert!(*from == admin1 || *from == admin2, "{}", message);
 
-        for id in competition_ids.iter() {
-            if let Some(mut comp) = env.storage().instance().get::<String, Competition>(&id) {
-                // Automatically finalize if time is past voting and not already finalized
-                if !comp.finalized && now > comp.vote_end {
-                    Self::internal_finalize(&env, &mut comp);
-                    // Save the updated competition after internal finalization
-                    env.storage().instance().set(&id, &comp);
+        from.require_auth();
+    }
+    // Internal function to compute the platform fee a competition charges on an amount
+    fn fee_for(comp: &Competition, amount: i128) -> i128 {
+        amount
+            .checked_mul(comp.fee_bps as i128)
+            .expect("Overflow in fee")
+            / 10_000
+    }
+    // Internal function to accrue a collected fee to the treasury balance of a token
+    fn accrue_fee(env: &Env, id: &String, token: &Address, fee: i128) {
+        if fee == 0 {
+            return;
+        }
+
+        let treasury_key = Symbol::new(env, "treasury_bal");
+        let mut balances: Map<Address, i128> = env
+            .storage()
+            .instance()
+            .get(&treasury_key)
+            .unwrap_or(Map::new(env));
+        let accrued = balances.get(token.clone()).unwrap_or(0);
+        balances.set(
+            token.clone(),
+            accrued.checked_add(fee).expect("Overflow in treasury"),
+        );
+        env.storage().instance().set(&treasury_key, &balances);
+
+        env.events().publish(
+            (Symbol::new(env, "fee_collected"), id.clone()),
+            (token.clone(), fee),
+        );
+    }
+    // Internal function to split one token's prize pool among the ranked artists by share_ratio,
+    // returns the rounding remainder rolled over to the successor
+    fn allocate_prizes(
+        env: &Env,
+        comp: &Competition,
+        artist_votes: &Vec<(String, u64)>,
+        grouped: &Vec<(u64, Vec<String>)>,
+        token: &Address,
+        prize_pool: i128,
+        payouts: &mut Vec<PendingPayout>,
+        records: &mut Vec<PayoutRecord>,
+    ) -> i128 {
+        let share = comp.share_ratio.clone();
+        let mut total_allocated = 0i128;
+
+        // Work out the combined share of every rewarded group of artists sharing a rank
+        let mut group_shares = Vec::new(env);
+        let mut rank_index = 0usize;
+        for (votes_in_group, group_artists) in grouped.iter() {
+            if votes_in_group == 0 || rank_index >= share.len().try_into().unwrap() {
+                break;
+            }
+
+            let group_size = group_artists.len();
+            let mut combined_share = 0i128;
+            for offset in 0..group_size {
+                let index = rank_index + offset as usize;
+                if index < share.len().try_into().unwrap() {
+                    combined_share += share.get(index as u32).unwrap() as i128;
                 }
+            }
+            group_shares.push_back((group_artists, combined_share));
+            rank_index += group_size as usize;
+        }
 
-                let is_submission_active =
-                    now >= comp.artist_add_start && now <= comp.artist_add_end;
-                let is_voting_active = now >= comp.vote_start && now <= comp.vote_end;
-                let is_finalized = comp.finalized;
+        // Divide once so tied artists don't lose the remainder of combined_share / group_size
+        let mut total_winner_share = 0i128;
+        for (group_artists, combined_share) in group_shares.iter() {
+            total_winner_share += combined_share;
+            for artist_sym in group_artists.iter() {
+                let amount = prize_pool
+                    .checked_mul(combined_share)
+                    .expect("Overflow in prize share")
+                    / (100 * group_artists.len() as i128);
+                if amount > 0 {
+                    Self::record_pending_payout(
+                        env,
+                        comp,
+                        payouts,
+                        records,
+                        artist_sym,
+                        token,
+                        amount,
+                        PayoutKind::Main,
+                    );
+                    total_allocated += amount;
+                }
+            }
+        }
 
-                if is_submission_active || is_voting_active || (now <= comp.vote_end + 86400) {
-                    active_competitions.push_back(CompetitionStatus {
-                        id: id.clone(),
-                        competition: comp,
-                        is_submission_active,
-                        is_voting_active,
-                        is_finalized,
+        // Handle leftover allocation proportionally among actual winners, tied artists get equal parts
+        let mut leftover = prize_pool - total_allocated;
+        if leftover > 0 && !artist_votes.is_empty() {
+            let mut leftover_allocated = 0i128;
+            for (group_artists, combined_share) in group_shares.iter() {
+                for artist_sym in group_artists.iter() {
+                    let proportional_amount = leftover
+                        .checked_mul(combined_share)
+                        .expect("Overflow in leftover share")
+                        .checked_div(total_winner_share * group_artists.len() as i128)
+                        .unwrap_or(0);
+
+                    if proportional_amount > 0 {
+                        Self::record_pending_payout(
+                            env,
+                            comp,
+                            payouts,
+                            records,
+                            artist_sym,
+                            token,
+                            proportional_amount,
+                            PayoutKind::Leftover,
+                        );
+                        leftover_allocated += proportional_amount;
+                    }
+                }
+            }
+            leftover -= leftover_allocated;
+
+            // Whatever the floor divisions left behind rolls over to the successor if it can take it
+            if let Some(successor) = comp.successor.clone() {
+                let next: Competition = env.storage().instance().get(&successor).unwrap();
+                if Self::can_take_funds(&next) {
+                    Self::roll_over(env, &comp.id, &successor, token, leftover);
+                    return leftover;
+                }
+            }
+
+            // Otherwise it goes to the top ranked artist
+            let (top_artist, top_votes) = artist_votes.get(0).unwrap();
+            if leftover > 0 && top_votes > 0 {
+                Self::record_pending_payout(
+                    env,
+                    comp,
+                    payouts,
+                    records,
+                    top_artist,
+                    token,
+                    leftover,
+                    PayoutKind::Leftover,
+                );
+            }
+        }
+        0
+    }
+    // Internal function to add a prize amount to an artist's pending payout in a token, merging repeated entries.
+    // The payout record of the same kind is updated alongside for the audit trail.
+    fn record_pending_payout(
+        env: &Env,
+        comp: &Competition,
+        payouts: &mut Vec<PendingPayout>,
+        records: &mut Vec<PayoutRecord>,
+        artist_name: String,
+        token: &Address,
+        amount: i128,
+        kind: PayoutKind,
+    ) {
+        let mut recorded = false;
+        for (i, record) in records.iter().enumerate() {
+            if record.artist == artist_name && record.token == *token && record.kind == kind {
+                let mut updated = record.clone();
+                updated.amount += amount;
+                records.set(i as u32, updated);
+                recorded = true;
+                break;
+            }
+        }
+        if !recorded {
+            let rank = comp
+                .ranking
+                .iter()
+                .find(|entry| entry.artist == artist_name)
+                .map(|entry| entry.rank)
+                .unwrap_or(0);
+            for (addr, name) in comp.artists.iter() {
+                if name == artist_name {
+                    records.push_back(PayoutRecord {
+                        artist: artist_name.clone(),
+                        address: addr,
+                        token: token.clone(),
+                        amount,
+                        rank,
+                        kind,
+                        status: PayoutStatus::Pending,
+                        timestamp: env.ledger().timestamp(),
                     });
+                    break;
                 }
             }
         }
 
-        active_competitions
+        for (i, payout) in payouts.iter().enumerate() {
+            if payout.artist == artist_name && payout.token == *token {
+                let mut updated = payout.clone();
+                updated.amount += amount;
+                payouts.set(i as u32, updated);
+                return;
+            }
+        }
+        for (addr, name) in comp.artists.iter() {
+            if name == artist_name {
+                payouts.push_back(PendingPayout {
+                    artist: artist_name,
+                    address: addr,
+                    token: token.clone(),
+                    amount,
+                    attempts: 0,
+                });
+                return;
+            }
+        }
+    }
+    // Internal function to update the status of an artist's payout records in a token
+    fn set_payout_status(env: &Env, id: &String, artist: &String, token: &Address, status: PayoutStatus) {
+        let payouts_key = Symbol::new(env, "payouts");
+        let mut all_records: Map<String, Vec<PayoutRecord>> = env
+            .storage()
+            .instance()
+            .get(&payouts_key)
+            .unwrap_or(Map::new(env));
+        let mut records = all_records.get(id.clone()).unwrap_or(Vec::new(env));
+
+        for (i, record) in records.clone().iter().enumerate() {
+            if record.artist == *artist && record.token == *token {
+                let mut updated = record.clone();
+                updated.status = status;
+                updated.timestamp = env.ledger().timestamp();
+                records.set(i as u32, updated);
+            }
+        }
+        all_records.set(id.clone(), records);
+        env.storage().instance().set(&payouts_key, &all_records);
+    }
+    // Internal function to add an artist and their artwork to a competition, splitting an entry fee already
+    // collected between the pot and the treasury. The treasury part is held until prizes are allocated so it
+    // can be refunded. The caller saves the competition afterwards.
+    fn admit_artist(
+        env: &Env,
+        id: &String,
+        comp: &mut Competition,
+        artist_address: Address,
+        artist_name: String,
+        artwork: ArtworkMetadata,
+        fee_paid: i128,
+    ) {
+        Self::push_artwork(env, comp, &artist_name, artwork);
+
+        // Add artist to the list
+        comp.artists.push_back((artist_address.clone(), artist_name.clone()));
+        if Self::adjust_artist_stats(env, &artist_address, 1, 0) {
+            let mut credit = Self::stats_credit(env, id);
+            credit.participated.push_back(artist_address.clone());
+            Self::save_stats_credit(env, id, credit);
+        }
+        Self::mark_pending(env, comp, &artist_name);
+
+        if fee_paid > 0 {
+            let entry_fee = comp.entry_fee.clone();
+            let pot_amount = fee_paid * entry_fee.pot_pct as i128 / 100;
+            let pot = comp.pots.get(entry_fee.token.clone()).unwrap_or(0);
+            comp.pots.set(
+                entry_fee.token.clone(),
+                pot.checked_add(pot_amount).expect("Overflow in pot"),
+            );
+            let held = comp.fees_held.get(entry_fee.token.clone()).unwrap_or(0);
+            comp.fees_held
+                .set(entry_fee.token.clone(), held + fee_paid - pot_amount);
+
+            let entry_fees_key = Symbol::new(env, "entry_fees");
+            let mut all_entry_fees: Map<String, Vec<EntryFeePayment>> = env
+                .storage()
+                .instance()
+                .get(&entry_fees_key)
+                .unwrap_or(Map::new(env));
+            let mut payments = all_entry_fees.get(id.clone()).unwrap_or(Vec::new(env));
+            payments.push_back(EntryFeePayment {
+                artist: artist_name,
+                address: artist_address,
+                token: entry_fee.token,
+                amount: fee_paid,
+                pot_amount,
+                refund_due: false,
+            });
+            all_entry_fees.set(id.clone(), payments);
+            env.storage().instance().set(&entry_fees_key, &all_entry_fees);
+        }
     }
+    // Internal function to add an artwork to an artist's submission, rejecting duplicates and artworks over the cap
+    fn push_artwork(env: &Env, comp: &mut Competition, artist_name: &String, artwork: ArtworkMetadata) {
+        // Check for duplicate metadata
+        let mut artworks = comp
+            .artist_metadata
+            .get(artist_name.clone())
+            .unwrap_or_else(|| Vec::new(env));
 
-    /// Get the competition details
-    pub fn get_competition(env: Env, id: String) -> Competition {
-        env.storage().instance().get(&id).unwrap()
-    }
+        assert!(
+            comp.max_artworks_per_artist == 0 || artworks.len() < comp.max_artworks_per_artist,
+            "Artwork limit reached"
+        );
 
-    /// View all submitted artists and their metadata for a competition
-    pub fn get_comp_artists(env: Env, id: String) -> Vec<(String, ArtworkMetadata)> {
-        let comp: Competition = env.storage().instance().get(&id).unwrap();
+        Self::check_duplicate_artwork(&artworks, &artwork, None);
+        artworks.push_back(artwork);
 
-        let mut result = Vec::new(&env);
-        for (artist, artworks) in comp.artist_metadata.iter() {
-            for art in artworks.iter() {
-                result.push_back((artist.clone(), art));
+        comp.artist_metadata.set(artist_name.clone(), artworks);
+    }
+    // Internal function to reject an artwork repeating the name, description or image of another artwork,
+    // `skip` is the index of the artwork being edited
+    fn check_duplicate_artwork(artworks: &Vec<ArtworkMetadata>, artwork: &ArtworkMetadata, skip: Option<u32>) {
+        for (i, item) in artworks.iter().enumerate() {
+            if skip == Some(i as u32) {
+                continue;
+            }
+            if item.artwork_name == artwork.artwork_name {
+                panic!("Artwork name already exists");
+            }
+            if item.description == artwork.description {
+                panic!("Description already exists");
+            }
+            if item.img_url == artwork.img_url {
+                panic!("Image URL already exists");
             }
         }
-
-        result
     }
+    // Internal function to check the submission window is open and `from` submitted as `artist_name`
+    fn require_submitting_artist(env: &Env, comp: &mut Competition, artist_name: &String, from: &Address) {
+        Self::require_running(env, comp);
+        Self::sync_state(env, comp);
+        let now = env.ledger().timestamp();
+        assert!(
+            comp.state == CompetitionState::Submissions
+                && now >= comp.artist_add_start
+                && now <= comp.artist_add_end,
+            "Submission window closed"
+        );
 
-    /// Get the total pot for a competition
-    pub fn get_pot(env: Env, id: String) -> u64 {
-        let comp: Competition = env.storage().instance().get(&id).unwrap();
-        comp.pot
-    }
+        assert!(
+            comp.artists
+                .iter()
+                .any(|(addr, name)| name == *artist_name && addr == *from),
+            "Only the submitting artist can change the submission"
+        );
 
-    /// Get the minimum token amount required to vote in a competition
-    pub fn get_min_vote_tokens(env: Env, id: String) -> u64 {
-        let comp: Competition = env.storage().instance().get(&id).unwrap();
-        comp.min_vote_tokens
+        from.require_auth();
     }
-
-    /// Get all contestants ranked by votes from winner to last place
-    pub fn get_winner(env: Env, id: String) -> Vec<ArtistRanking> {
-        let comp: Competition = env.storage().instance().get(&id).unwrap();
-
-        // Collect all artists with their votes
-        let mut artist_votes = Vec::new(&env);
-        for artist in comp.artists.iter() {
-            let votes = comp.votes.get(artist.1.clone()).unwrap_or(0);
-            artist_votes.push_back((artist.1.clone(), votes));
+    // Internal function to send a submission of a moderated competition back to review after it changed
+    fn mark_pending(env: &Env, comp: &mut Competition, artist_name: &String) {
+        if !comp.moderated {
+            return;
+        }
+        comp.reviews.set(
+            artist_name.clone(),
+            SubmissionReview {
+                status: ReviewStatus::Pending,
+                reason: None,
+                reviewed_at: env.ledger().timestamp(),
+            },
+        );
+    }
+    // Internal function to take an artist out of the ranking, recording why. A finalized ranking is recounted
+    // at once, everyone ranked below moves up one place and tie-breaks already applied stay as they were.
+    // An unfinalized competition ranks without them later. The caller saves the competition afterwards.
+    fn exclude_from_ranking(env: &Env, comp: &mut Competition, artist_name: &String, reason: String) {
+        comp.disqualified.set(artist_name.clone(), reason);
+        if comp.state != CompetitionState::Finalized {
+            return;
         }
 
-        // Sort by votes (descending)
-        let len = artist_votes.len();
-        for i in 0..len {
-            for j in 0..(len - 1 - i) {
-                let current_votes = artist_votes.get(j).unwrap().1;
-                let next_votes = artist_votes.get(j + 1).unwrap().1;
-                if current_votes < next_votes {
-                    let temp = artist_votes.get(j).unwrap();
-                    let next = artist_votes.get(j + 1).unwrap();
-                    artist_votes.set(j, next);
-                    artist_votes.set(j + 1, temp);
-                }
+        let previous = comp.ranking.clone();
+        let removed_rank = previous
+            .iter()
+            .find(|entry| entry.artist == *artist_name)
+            .map(|entry| entry.rank);
+
+        let mut ranking = Vec::new(env);
+        for mut entry in previous.iter() {
+            if entry.artist == *artist_name {
+                continue;
             }
+            if removed_rank.is_some_and(|rank| entry.rank > rank) {
+                entry.rank -= 1;
+            }
+            entry.is_winner = entry.rank == 1 && entry.votes > 0;
+            ranking.push_back(entry);
         }
+        comp.winner = match ranking.get(0) {
+            Some(top) if top.votes > 0 => Some(top.artist),
+            _ => None,
+        };
+        comp.ranking = ranking;
 
-        // Create ranking with positions
-        let mut rankings = Vec::new(&env);
-        for (i, (artist, votes)) in artist_votes.iter().enumerate() {
-            rankings.push_back(ArtistRanking {
-                artist,
-                votes,
-                rank: (i + 1) as u32,
-                is_winner: i == 0 && votes > 0,
-            });
+        // Move the credited wins from the old winners to the new ones
+        let mut credit = Self::stats_credit(env, &comp.id);
+        for (addr, name) in comp.artists.iter() {
+            let was_winner = previous
+                .iter()
+                .any(|entry| entry.is_winner && entry.artist == name);
+            let is_winner = Self::is_winner(comp, &name);
+            if let (Some(index), false) = (credit.won.first_index_of(&addr), is_winner) {
+                Self::adjust_artist_stats(env, &addr, 0, -1);
+                credit.won.remove(index);
+            } else if !was_winner && is_winner && Self::adjust_artist_stats(env, &addr, 0, 1) {
+                credit.won.push_back(addr);
+            }
         }
-
-        rankings
+        Self::save_stats_credit(env, &comp.id, credit);
     }
-
-    /// Get all artists and their info stored on the contract
-    pub fn get_artists(env: Env) -> Vec<(Address, ArtistInfo)> {
-        let artist_info_key = Symbol::new(&env, "artist_info");
-        let all_info: Map<Address, ArtistInfo> = env
+    // Internal function to check whether a submission is listed and votable: approved when moderated,
+    // not hidden by reports and not disqualified
+    fn is_listed(comp: &Competition, artist_name: &String) -> bool {
+        Self::is_ranked(comp, artist_name) && !comp.hidden.contains(artist_name)
+    }
+    // Internal function to check whether a submission takes part in the ranking: approved when moderated and
+    // not disqualified. Submissions hidden by open reports keep their place until the reports are upheld.
+    fn is_ranked(comp: &Competition, artist_name: &String) -> bool {
+        let approved = !comp.moderated
+            || comp
+                .reviews
+                .get(artist_name.clone())
+                .is_some_and(|review| review.status == ReviewStatus::Approved);
+        approved && !comp.disqualified.contains_key(artist_name.clone())
+    }
+    // Internal function to resolve the open reports against an artist, or every artist, refunding their deposits
+    // or forfeiting them to the treasury. A failed refund stays on the report until claim_report_refund.
+    fn close_reports(env: &Env, id: &String, comp: &Competition, artist: Option<&String>, refund: bool) {
+        let reports_key = Symbol::new(env, "reports");
+        let mut all_reports: Map<String, Vec<Report>> = env
             .storage()
             .instance()
-            .get(&artist_info_key)
-            .unwrap_or(Map::new(&env));
-        let mut result = Vec::new(&env);
-        for (addr, info) in all_info.iter() {
-            result.push_back((addr, info));
+            .get(&reports_key)
+            .unwrap_or(Map::new(env));
+        let mut reports = all_reports.get(id.clone()).unwrap_or(Vec::new(env));
+
+        for (i, mut report) in reports.clone().iter().enumerate() {
+            if report.resolved || artist.is_some_and(|name| *name != report.artist) {
+                continue;
+            }
+            report.resolved = true;
+            if report.deposit > 0 {
+                if !refund {
+                    Self::accrue_fee(env, id, &comp.token, report.deposit);
+                    report.deposit = 0;
+                } else if Self::try_pay_out(env, &comp.token, &report.reporter, report.deposit) {
+                    report.deposit = 0;
+                }
+            }
+            reports.set(i as u32, report);
         }
-        result
+        all_reports.set(id.clone(), reports);
+        env.storage().instance().set(&reports_key, &all_reports);
     }
+    // Internal function to take an artist out of a competition with their artworks and votes.
+    // The caller saves the competition afterwards.
+    fn drop_artist(env: &Env, id: &String, comp: &mut Competition, artist_name: &String, refund: bool) {
+        // Find and remove the artist from the artists list
+        let mut new_artists = Vec::new(env);
+        let mut artist_found = false;
+        let mut artist_address_to_remove: Option<Address> = None;
+        for (addr, name) in comp.artists.iter() {
+            if name != *artist_name {
+                new_artists.push_back((addr, name));
+            } else {
+                artist_found = true;
+                artist_address_to_remove = Some(addr);
+            }
+        }
 
-    /// Get artist info for a specific address
-    pub fn get_artist_info(env: Env, address: Address) -> Option<ArtistInfo> {
-        let artist_info_key = Symbol::new(&env, "artist_info");
-        let all_info: Map<Address, ArtistInfo> = env
-            .storage()
-            .instance()
-            .get(&artist_info_key)
-            .unwrap_or(Map::new(&env));
-        all_info.get(address)
-    }
+        assert!(artist_found, "Artist not found in competition");
+        comp.artists = new_artists;
 
-    /// Get vote history for a competition
-    pub fn get_vote_history(env: Env, id: String) -> Vec<VoteHistory> {
-        let vote_history_key = Symbol::new(&env, "vote_hist");
-        let all_vote_history: Map<String, Vec<VoteHistory>> = env
-            .storage()
-            .instance()
-            .get(&vote_history_key)
-            .unwrap_or(Map::new(&env));
+        if refund {
+            Self::refund_entry_fee(env, id, comp, artist_name);
+        }
 
-        all_vote_history.get(id).unwrap_or(Vec::new(&env))
-    }
+        // Remove artist metadata
+        comp.artist_metadata.remove(artist_name.clone());
+        comp.reviews.remove(artist_name.clone());
+        if let Some(index) = comp.hidden.first_index_of(artist_name) {
+            comp.hidden.remove(index);
+        }
+        Self::close_reports(env, id, comp, Some(artist_name), true);
 
-    /// check if an wallet has registered artist info
-    pub fn has_registered(env: Env, address: Address) -> bool {
-        let artist_info_key = Symbol::new(&env, "artist_info");
-        let all_info: Map<Address, ArtistInfo> = env
+        // Remove any votes for this artist
+        comp.votes.remove(artist_name.clone());
+        comp.artwork_votes.remove(artist_name.clone());
+
+        // Remove any vote logs that voted for this artist and update vote history
+        let vote_history_key = Symbol::new(env, "vote_hist");
+        let mut all_vote_history: Map<String, Vec<VoteHistory>> = env
             .storage()
             .instance()
-            .get(&artist_info_key)
-            .unwrap_or(Map::new(&env));
-        all_info.contains_key(address)
-    }
-
-    /// Check if a voter voted
-    pub fn has_voted(env: Env, id: String, voter: Address) -> Option<String> {
-        let comp: Competition = env.storage().instance().get(&id).unwrap();
-        comp.vote_log.get(voter)
-    }
-
-    /// Check if a user can vote
-    pub fn check_voting_eligibility(env: Env, id: String, voter: Address) -> VotingEligibility {
-        let comp: Competition = env.storage().instance().get(&id).unwrap();
-        let now = env.ledger().timestamp();
+            .get(&vote_history_key)
+            .unwrap_or(Map::new(env));
 
-        let voting_active = now >= comp.vote_start && now <= comp.vote_end;
+        // Filter out votes for the removed artist from vote history
+        let comp_history = all_vote_history.get(id.clone()).unwrap_or(Vec::new(env));
+        let mut new_history = Vec::new(env);
+        let mut voters_to_clear = Vec::new(env);
 
-        // Get token decimals
-        let decimals: u32 =
-            env.invoke_contract(&comp.token, &Symbol::new(&env, "decimals"), vec![&env]);
-        let multiplier = 10u64.pow(decimals);
+        for vote_record in comp_history.iter() {
+            if vote_record.artist != *artist_name {
+                new_history.push_back(vote_record);
+            } else {
+                voters_to_clear.push_back(vote_record.voter.clone());
+            }
+        }
 
-        // Get voter's token balance
-        let voter_balance_stroop: i128 = env.invoke_contract(
-            &comp.token,
-            &Symbol::new(&env, "balance"),
-            vec![&env, voter.into_val(&env)],
-        );
-        let voter_balance_lumen = (voter_balance_stroop as u64) / multiplier;
+        // Clear vote log entries for voters who voted for the removed artist
+        for voter in voters_to_clear.iter() {
+            comp.vote_log.remove(voter.clone());
+        }
 
-        let current_balance = voter_balance_lumen;
-        let min_required = comp.min_vote_tokens;
-        let has_voted = comp.vote_log.contains_key(voter.clone());
-        let can_vote = voting_active && current_balance >= min_required && !has_voted;
+        all_vote_history.set(id.clone(), new_history);
+        env.storage()
+            .instance()
+            .set(&vote_history_key, &all_vote_history);
 
-        VotingEligibility {
-            can_vote,
-            has_voted,
-            current_balance,
-            min_required,
-            voting_active,
+        // Roll back the participation counter if it was counted, wins are only credited once the competition is finalized
+        let artist_address = artist_address_to_remove.unwrap();
+        let mut credit = Self::stats_credit(env, id);
+        if let Some(index) = credit.participated.first_index_of(&artist_address) {
+            Self::adjust_artist_stats(env, &artist_address, -1, 0);
+            credit.participated.remove(index);
+            Self::save_stats_credit(env, id, credit);
         }
+
+        // The freed slot goes to the next artist on the waitlist
+        Self::promote_waitlist(env, id, comp);
     }
-    /// Get the current contract version
-    pub fn version(env: Env) -> String {
-        String::from_str(&env, "1.0.1")
-    }
-    /// Get the current admins of the contract
-    pub fn get_admins(env: Env) -> (Option<Address>, Option<Address>) {
-        let admin1 = env
-            .storage()
-            .instance()
-            .get::<Symbol, Address>(&Symbol::new(&env, "admin1"));
-        let admin2 = env
+    // Internal function to move waitlisted submissions into free slots before voting starts.
+    // The caller saves the competition afterwards.
+    fn promote_waitlist(env: &Env, id: &String, comp: &mut Competition) {
+        if env.ledger().timestamp() >= comp.vote_start {
+            return;
+        }
+
+        let waitlist_key = Symbol::new(env, "waitlist");
+        let mut all_waitlists: Map<String, Vec<WaitlistEntry>> = env
             .storage()
             .instance()
-            .get::<Symbol, Address>(&Symbol::new(&env, "admin2"));
-        (admin1, admin2)
-    }
-    // Internal function to determine the winner without prize distribution
-    fn internal_finalize(env: &Env, comp: &mut Competition) {
-        // Early check: if no artists, mark as finalized with no winner
-        if comp.artists.len() == 0 {
-            comp.finalized = true;
-            comp.winner = None;
+            .get(&waitlist_key)
+            .unwrap_or(Map::new(env));
+        let mut waitlist = all_waitlists.get(id.clone()).unwrap_or(Vec::new(env));
+        if waitlist.is_empty() {
             return;
         }
 
-        // Collect all artists with their votes and sort them
-        let mut artist_votes = Vec::new(env);
-        for (_, artist_name) in comp.artists.iter() {
-            let votes = comp.votes.get(artist_name.clone()).unwrap_or(0);
-            artist_votes.push_back((artist_name.clone(), votes));
+        while !waitlist.is_empty() && (comp.max_artists == 0 || comp.artists.len() < comp.max_artists) {
+            let entry = waitlist.pop_front().unwrap();
+            Self::admit_artist(
+                env,
+                id,
+                comp,
+                entry.address,
+                entry.artist_name.clone(),
+                entry.artwork,
+                entry.fee_paid,
+            );
+            env.events()
+                .publish((Symbol::new(env, "promoted"), id.clone()), entry.artist_name);
         }
-
-        // Sort by votes (descending) - manual bubble sort
-        let len = artist_votes.len();
-        for i in 0..len {
-            for j in 0..(len - 1 - i) {
-                let current_votes = artist_votes.get(j).unwrap().1;
-                let next_votes = artist_votes.get(j + 1).unwrap().1;
-                if current_votes < next_votes {
-                    let temp = artist_votes.get(j).unwrap();
-                    let next = artist_votes.get(j + 1).unwrap();
-                    artist_votes.set(j, next);
-                    artist_votes.set(j + 1, temp);
-                }
+        all_waitlists.set(id.clone(), waitlist);
+        env.storage().instance().set(&waitlist_key, &all_waitlists);
+    }
+    // Internal function to refund an artist's entry fee, a failed transfer stays claimable with claim_refund.
+    // The caller saves the competition afterwards.
+    fn refund_entry_fee(env: &Env, id: &String, comp: &mut Competition, artist_name: &String) {
+        let entry_fees_key = Symbol::new(env, "entry_fees");
+        let mut all_entry_fees: Map<String, Vec<EntryFeePayment>> = env
+            .storage()
+            .instance()
+            .get(&entry_fees_key)
+            .unwrap_or(Map::new(env));
+        let mut payments = all_entry_fees.get(id.clone()).unwrap_or(Vec::new(env));
+
+        let mut index: Option<u32> = None;
+        for (i, payment) in payments.iter().enumerate() {
+            if payment.artist == *artist_name && !payment.refund_due {
+                index = Some(i as u32);
+                break;
             }
         }
+        let Some(index) = index else {
+            return;
+        };
+        let mut payment = payments.get(index).unwrap();
 
-        // Set winner (top artist with votes > 0) - handle case where no votes were cast
-        if artist_votes.len() > 0 && artist_votes.get(0).unwrap().1 > 0 {
-            let winner_symbol = artist_votes.get(0).unwrap().0.clone();
-            comp.winner = Some(winner_symbol.clone());
+        // Take the fee back out of the pot and the held treasury share
+        let pot = comp.pots.get(payment.token.clone()).unwrap_or(0);
+        let held = comp.fees_held.get(payment.token.clone()).unwrap_or(0);
+        let held_amount = payment.amount - payment.pot_amount;
+        assert!(
+            pot >= payment.pot_amount && held >= held_amount,
+            "Insufficient funds for refund"
+        );
+        comp.pots
+            .set(payment.token.clone(), pot - payment.pot_amount);
+        comp.fees_held
+            .set(payment.token.clone(), held - held_amount);
 
-            // Increment competitions_won for the winning artist
-            let mut winner_address: Option<Address> = None;
-            for (addr, name) in comp.artists.iter() {
-                if name == winner_symbol {
-                    winner_address = Some(addr);
-                    break;
+        if Self::try_pay_out(env, &payment.token, &payment.address, payment.amount) {
+            payments.remove(index);
+        } else {
+            payment.refund_due = true;
+            payments.set(index, payment);
+        }
+        all_entry_fees.set(id.clone(), payments);
+        env.storage().instance().set(&entry_fees_key, &all_entry_fees);
+    }
+    // Internal function to sum what a competition's pot of a token still owes: prizes, vesting positions and
+    // voter rewards once paid out, contributions and entry fees that could be refunded before that
+    fn owed_from_pot(env: &Env, id: &String, comp: &Competition, token: &Address) -> i128 {
+        let mut owed = 0i128;
+        if comp.state == CompetitionState::Paid {
+            for payout in Self::get_pending_payouts(env.clone(), id.clone()).iter() {
+                if payout.token == *token {
+                    owed += payout.amount;
                 }
             }
-            if let Some(addr) = winner_address {
-                let artist_info_key = Symbol::new(env, "artist_info");
-                let mut all_info: Map<Address, ArtistInfo> = env
-                    .storage()
-                    .instance()
-                    .get(&artist_info_key)
-                    .unwrap_or(Map::new(env));
-                if let Some(mut info) = all_info.get(addr.clone()) {
-                    info.competitions_won += 1;
-                    all_info.set(addr, info);
-                    env.storage().instance().set(&artist_info_key, &all_info);
+            let all_positions: Map<String, Vec<VestingPosition>> = env
+                .storage()
+                .instance()
+                .get(&Symbol::new(env, "vesting"))
+                .unwrap_or(Map::new(env));
+            for position in all_positions.get(id.clone()).unwrap_or(Vec::new(env)).iter() {
+                if position.token == *token {
+                    owed += position.total - position.claimed;
                 }
             }
+            if let Some(pool) = Self::get_voter_reward(env.clone(), id.clone()) {
+                let unclaimed_voters = pool.eligible_voters - pool.claimed.len();
+                owed += pool.per_voter.get(token.clone()).unwrap_or(0) * unclaimed_voters as i128;
+            }
         } else {
-            // No votes cast - just mark as finalized with no winner
-            comp.winner = None;
+            for sponsor in Self::get_sponsors(env.clone(), id.clone()).iter() {
+                if sponsor.token == *token && !sponsor.refunded {
+                    owed += sponsor.amount;
+                }
+            }
+            for payment in Self::get_entry_fees(env.clone(), id.clone()).iter() {
+                if payment.token == *token && !payment.refund_due {
+                    owed += payment.pot_amount;
+                }
+            }
+        }
+        owed
+    }
+    // Internal function to add an undistributed amount to the successor's pot, the caller takes it off its own pot
+    fn roll_over(env: &Env, id: &String, successor: &String, token: &Address, amount: i128) {
+        if amount == 0 {
+            return;
         }
 
-        comp.finalized = true;
+        let mut next: Competition = env.storage().instance().get(successor).unwrap();
+        assert!(Self::can_take_funds(&next), "Successor cannot take the pot");
+        let pot = next.pots.get(token.clone()).unwrap_or(0);
+        next.pots.set(
+            token.clone(),
+            pot.checked_add(amount).expect("Overflow in pot"),
+        );
+        env.storage().instance().set(successor, &next);
+
+        env.events().publish(
+            (Symbol::new(env, "rolled_over"), id.clone()),
+            (successor.clone(), token.clone(), amount),
+        );
+    }
+    // Internal function to compute how much of a vesting position has unlocked at a time
+    fn vested_amount(schedule: &PayoutSchedule, position: &VestingPosition, now: u64) -> i128 {
+        let elapsed = now.saturating_sub(position.start);
+        if elapsed < schedule.cliff {
+            return position.claimed;
+        }
+        if elapsed >= schedule.duration {
+            return position.total;
+        }
+        // Never report less than what was already withdrawn, e.g. after an expired position was closed
+        (position.total * elapsed as i128 / schedule.duration as i128).max(position.claimed)
+    }
+    // Internal function to transfer a payout from the contract, returns false instead of panicking
+    fn try_pay_out(env: &Env, token: &Address, to: &Address, amount: i128) -> bool {
+        matches!(
+            env.try_invoke_contract::<(), soroban_sdk::InvokeError>(
+                token,
+                &symbol_short!("transfer"),
+                vec![
+                    env,
+                    env.current_contract_address().into_val(env),
+                    to.into_val(env),
+                    amount.into_val(env),
+                ],
+            ),
+            Ok(Ok(()))
+        )
     }
 }
+
+mod test;
@@ -0,0 +1,1328 @@
+#![cfg(test)]
+
+use super::*;
+use soroban_sdk::testutils::{Address as _, Ledger};
+use soroban_sdk::token::{StellarAssetClient, TokenClient};
+
+struct Setup {
+    env: Env,
+    client: CompetitionContractClient<'static>,
+    contract: Address,
+    admin: Address,
+    token: Address,
+}
+
+fn setup() -> Setup {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let admin2 = Address::generate(&env);
+    let contract = env.register(CompetitionContract, (admin.clone(), admin2));
+    let client = CompetitionContractClient::new(&env, &contract);
+
+    // Revocable so tests can make transfers to an address fail
+    let asset = env.register_stellar_asset_contract_v2(admin.clone());
+    asset
+        .issuer()
+        .set_flag(soroban_sdk::testutils::IssuerFlags::RevocableFlag);
+    let token = asset.address();
+
+    Setup {
+        env,
+        client,
+        contract,
+        admin,
+        token,
+    }
+}
+
+fn text(env: &Env, value: &str) -> String {
+    String::from_str(env, value)
+}
+
+fn set_time(t: &Setup, timestamp: u64) {
+    t.env.ledger().with_mut(|ledger| ledger.timestamp = timestamp);
+}
+
+fn mint(t: &Setup, to: &Address, amount: i128) {
+    StellarAssetClient::new(&t.env, &t.token).mint(to, &amount);
+}
+
+fn balance(t: &Setup, of: &Address) -> i128 {
+    TokenClient::new(&t.env, &t.token).balance(of)
+}
+
+fn set_authorized(t: &Setup, address: &Address, authorized: bool) {
+    StellarAssetClient::new(&t.env, &t.token).set_authorized(address, &authorized);
+}
+
+// Submissions run 100-200 and voting 300-400
+fn create(t: &Setup, id: &str) -> String {
+    let id = text(&t.env, id);
+    set_time(t, 10);
+    t.client.create_competition(
+        &t.admin,
+        &id,
+        &text(&t.env, "Test competition"),
+        &100,
+        &200,
+        &300,
+        &400,
+        &t.token,
+        &0,
+    );
+    id
+}
+
+fn fund(t: &Setup, id: &String, amount: i128) -> Address {
+    let funder = Address::generate(&t.env);
+    mint(t, &funder, amount);
+    t.client.fund_pot(id, &funder, &t.token, &amount);
+    funder
+}
+
+fn submit(t: &Setup, id: &String, name: &str) -> Address {
+    let artist = Address::generate(&t.env);
+    let env = &t.env;
+    t.client.submit_art(
+        id,
+        &artist,
+        &text(env, name),
+        &text(env, name),
+        &text(env, name),
+        &text(env, name),
+    );
+    artist
+}
+
+fn vote(t: &Setup, id: &String, artist: &str, count: u32) {
+    for _ in 0..count {
+        t.client
+            .vote(id, &Address::generate(&t.env), &text(&t.env, artist));
+    }
+}
+
+#[test]
+fn test_claim_prizes_then_archive() {
+    let t = setup();
+    let id = create(&t, "claim");
+    fund(&t, &id, 1000);
+
+    set_time(&t, 150);
+    let alice = submit(&t, &id, "alice");
+    let bob = submit(&t, &id, "bob");
+    set_time(&t, 350);
+    vote(&t, &id, "alice", 2);
+    vote(&t, &id, "bob", 1);
+
+    set_time(&t, 500);
+    t.client.pay_winners(&id);
+    t.client.claim_prize(&id, &text(&t.env, "alice"));
+    t.client.claim_prize(&id, &text(&t.env, "bob"));
+
+    assert_eq!(balance(&t, &alice) + balance(&t, &bob), 1000);
+    assert!(balance(&t, &alice) > balance(&t, &bob));
+    assert_eq!(t.client.get_pot(&id), 0);
+    t.client.archive_competition(&id, &t.admin);
+    assert_eq!(balance(&t, &t.contract), 0);
+}
+
+#[test]
+fn test_unclaimed_prize_expires() {
+    let t = setup();
+    t.client.set_claim_window(&t.admin, &100);
+    let id = create(&t, "expiry");
+    fund(&t, &id, 1000);
+
+    set_time(&t, 150);
+    submit(&t, &id, "alice");
+    set_time(&t, 350);
+    vote(&t, &id, "alice", 1);
+
+    set_time(&t, 500);
+    t.client.pay_winners(&id);
+    let payouts = t.client.get_pending_payouts(&id);
+    assert_eq!(payouts.len(), 1);
+    assert_eq!(payouts.get(0).unwrap().amount, 1000);
+    assert!(t.client.try_reclaim_expired_prizes(&id, &t.admin).is_err());
+
+    set_time(&t, 601);
+    assert!(t.client.try_claim_prize(&id, &text(&t.env, "alice")).is_err());
+    t.client.reclaim_expired_prizes(&id, &t.admin);
+    assert_eq!(balance(&t, &t.admin), 1000);
+    assert_eq!(t.client.get_pending_payouts(&id).len(), 0);
+}
+
+#[test]
+fn test_failed_claim_is_retried() {
+    let t = setup();
+    let id = create(&t, "retry");
+    fund(&t, &id, 1000);
+
+    set_time(&t, 150);
+    let alice = submit(&t, &id, "alice");
+    // Keep a balance entry so the token can freeze it
+    mint(&t, &alice, 1);
+    set_time(&t, 350);
+    vote(&t, &id, "alice", 1);
+
+    set_time(&t, 500);
+    t.client.pay_winners(&id);
+    set_authorized(&t, &alice, false);
+    t.client.claim_prize(&id, &text(&t.env, "alice"));
+    assert_eq!(t.client.get_pending_payouts(&id).len(), 1);
+    assert!(t.client.try_archive_competition(&id, &t.admin).is_err());
+
+    set_authorized(&t, &alice, true);
+    t.client.retry_payouts(&id);
+    assert_eq!(t.client.get_pending_payouts(&id).len(), 0);
+    assert_eq!(balance(&t, &alice), 1001);
+    t.client.archive_competition(&id, &t.admin);
+}
+
+#[test]
+fn test_fractional_pot_is_split_exactly() {
+    let t = setup();
+    let id = create(&t, "fractional");
+    fund(&t, &id, 1001);
+
+    set_time(&t, 150);
+    let alice = submit(&t, &id, "alice");
+    let bob = submit(&t, &id, "bob");
+    let carol = submit(&t, &id, "carol");
+    set_time(&t, 350);
+    vote(&t, &id, "alice", 3);
+    vote(&t, &id, "bob", 2);
+    vote(&t, &id, "carol", 1);
+
+    // 50/30/20 of 1001 floors to 500/300/200, the base unit left over goes to the top artist
+    set_time(&t, 500);
+    t.client.pay_winners(&id);
+    for name in ["alice", "bob", "carol"] {
+        t.client.claim_prize(&id, &text(&t.env, name));
+    }
+    assert_eq!(balance(&t, &alice), 501);
+    assert_eq!(balance(&t, &bob), 300);
+    assert_eq!(balance(&t, &carol), 200);
+    assert_eq!(t.client.get_pot(&id), 0);
+}
+
+#[test]
+fn test_min_vote_tokens_in_base_units() {
+    let t = setup();
+    let id = text(&t.env, "minimum");
+    set_time(&t, 10);
+    t.client.create_competition(
+        &t.admin,
+        &id,
+        &text(&t.env, "Test competition"),
+        &100,
+        &200,
+        &300,
+        &400,
+        &t.token,
+        &15,
+    );
+
+    set_time(&t, 150);
+    submit(&t, &id, "alice");
+    set_time(&t, 350);
+    let short = Address::generate(&t.env);
+    mint(&t, &short, 14);
+    let eligibility = t.client.check_voting_eligibility(&id, &short);
+    assert!(!eligibility.can_vote);
+    assert_eq!(eligibility.current_balance, 14);
+    assert!(t.client.try_vote(&id, &short, &text(&t.env, "alice")).is_err());
+
+    let enough = Address::generate(&t.env);
+    mint(&t, &enough, 15);
+    t.client.vote(&id, &enough, &text(&t.env, "alice"));
+    assert!(t.client.try_fund_pot(&id, &enough, &t.token, &-1).is_err());
+}
+
+#[test]
+fn test_fee_on_payout_goes_to_treasury() {
+    let t = setup();
+    t.client.set_platform_fee(&t.admin, &1000, &FeeMode::OnPayout);
+    let treasury = Address::generate(&t.env);
+    t.client.set_treasury(&t.admin, &treasury);
+    let id = create(&t, "onpayout");
+    fund(&t, &id, 1000);
+    assert_eq!(t.client.get_pot(&id), 1000);
+
+    set_time(&t, 150);
+    let alice = submit(&t, &id, "alice");
+    set_time(&t, 350);
+    vote(&t, &id, "alice", 1);
+
+    // Shares are computed on the pot net of the 10% fee
+    set_time(&t, 500);
+    t.client.pay_winners(&id);
+    assert_eq!(t.client.get_treasury_balance(&t.token), 100);
+    t.client.claim_prize(&id, &text(&t.env, "alice"));
+    assert_eq!(balance(&t, &alice), 900);
+
+    t.client.withdraw_treasury(&t.admin, &t.token);
+    assert_eq!(balance(&t, &treasury), 100);
+    assert_eq!(t.client.get_treasury_balance(&t.token), 0);
+}
+
+#[test]
+fn test_fee_on_fund_is_held_until_payout() {
+    let t = setup();
+    t.client.set_platform_fee(&t.admin, &1000, &FeeMode::OnFund);
+    let id = create(&t, "onfund");
+    fund(&t, &id, 1000);
+    assert_eq!(t.client.get_pot(&id), 900);
+    assert_eq!(t.client.get_treasury_balance(&t.token), 0);
+
+    set_time(&t, 150);
+    let alice = submit(&t, &id, "alice");
+    set_time(&t, 350);
+    vote(&t, &id, "alice", 1);
+
+    set_time(&t, 500);
+    t.client.pay_winners(&id);
+    assert_eq!(t.client.get_treasury_balance(&t.token), 100);
+    t.client.claim_prize(&id, &text(&t.env, "alice"));
+    assert_eq!(balance(&t, &alice), 900);
+}
+
+#[test]
+fn test_voter_reward_claim() {
+    let t = setup();
+    let id = create(&t, "voters");
+    t.client
+        .set_voter_reward(&id, &t.admin, &10, &VoterRewardMode::WinnerBackers);
+    fund(&t, &id, 1000);
+
+    set_time(&t, 150);
+    submit(&t, &id, "alice");
+    submit(&t, &id, "bob");
+    set_time(&t, 350);
+    let backer = Address::generate(&t.env);
+    t.client.vote(&id, &backer, &text(&t.env, "alice"));
+    vote(&t, &id, "alice", 1);
+    let other = Address::generate(&t.env);
+    t.client.vote(&id, &other, &text(&t.env, "bob"));
+
+    set_time(&t, 500);
+    t.client.pay_winners(&id);
+    t.client.claim_voter_reward(&id, &backer);
+    // 10% of the pot shared by the two backers of the winner
+    assert_eq!(balance(&t, &backer), 50);
+    assert!(t.client.try_claim_voter_reward(&id, &backer).is_err());
+    assert!(t.client.try_claim_voter_reward(&id, &other).is_err());
+}
+
+#[test]
+fn test_cancel_refunds_funders() {
+    let t = setup();
+    let id = create(&t, "cancel");
+    let first = fund(&t, &id, 700);
+    let second = fund(&t, &id, 300);
+
+    t.client.cancel_competition(&id, &t.admin);
+    t.client.claim_refund(&id, &first);
+    t.client.claim_refund(&id, &second);
+    assert_eq!(balance(&t, &first), 700);
+    assert_eq!(balance(&t, &second), 300);
+    assert!(t.client.try_claim_refund(&id, &first).is_err());
+    assert_eq!(t.client.get_pot(&id), 0);
+}
+
+#[test]
+fn test_emergency_withdraw_leaves_owed_funds() {
+    let t = setup();
+    let id = create(&t, "emergency");
+    let funder = fund(&t, &id, 1000);
+    let to = Address::generate(&t.env);
+
+    // Contributions can still be refunded before payout
+    assert!(t
+        .client
+        .try_emergency_withdraw(&id, &t.admin, &t.token, &to, &1)
+        .is_err());
+
+    set_time(&t, 150);
+    let alice = submit(&t, &id, "alice");
+    set_time(&t, 350);
+    vote(&t, &id, "alice", 1);
+
+    // Allocated prizes are owed to the winner until claimed
+    set_time(&t, 500);
+    t.client.pay_winners(&id);
+    assert!(t
+        .client
+        .try_emergency_withdraw(&id, &t.admin, &t.token, &to, &1)
+        .is_err());
+    t.client.claim_prize(&id, &text(&t.env, "alice"));
+    assert_eq!(balance(&t, &alice), 1000);
+
+    // Cancelled competitions keep everything until every funder is refunded
+    let other = create(&t, "other");
+    fund(&t, &other, 500);
+    t.client.cancel_competition(&other, &t.admin);
+    assert!(t
+        .client
+        .try_emergency_withdraw(&other, &t.admin, &t.token, &to, &1)
+        .is_err());
+    assert_eq!(balance(&t, &to), 0);
+    assert_eq!(balance(&t, &funder), 0);
+}
+
+#[test]
+fn test_sponsor_ledger_records_contributions() {
+    let t = setup();
+    let id = create(&t, "sponsors");
+    let small = fund(&t, &id, 200);
+    let large = fund(&t, &id, 500);
+    mint(&t, &small, 400);
+    t.client.fund_pot(&id, &small, &t.token, &400);
+    t.client.set_sponsor_info(
+        &id,
+        &large,
+        &Some(text(&t.env, "Frog DAO")),
+        &None,
+        &Some(text(&t.env, "https://example.com")),
+    );
+
+    let sponsors = t.client.get_sponsors(&id);
+    assert_eq!(sponsors.len(), 2);
+    assert_eq!(sponsors.get(0).unwrap().funder, small);
+    assert_eq!(sponsors.get(0).unwrap().amount, 600);
+    assert_eq!(sponsors.get(1).unwrap().display_name, Some(text(&t.env, "Frog DAO")));
+    assert_eq!(t.client.get_contribution(&id, &large, &t.token), 500);
+}
+
+#[test]
+fn test_pot_paid_out_in_every_token() {
+    let t = setup();
+    let usdc = t.env.register_stellar_asset_contract_v2(t.admin.clone()).address();
+    let id = create(&t, "tokens");
+    let funder = fund(&t, &id, 1000);
+    StellarAssetClient::new(&t.env, &usdc).mint(&funder, &300);
+    assert!(t.client.try_fund_pot(&id, &funder, &usdc, &300).is_err());
+
+    t.client.approve_pot_token(&t.admin, &usdc);
+    t.client.fund_pot(&id, &funder, &usdc, &300);
+    assert_eq!(t.client.get_pots(&id).get(usdc.clone()), Some(300));
+    assert_eq!(t.client.get_contribution(&id, &funder, &usdc), 300);
+    let sponsors = t.client.get_sponsors(&id);
+    assert_eq!(sponsors.len(), 2);
+    assert_eq!(sponsors.get(0).unwrap().token, t.token);
+    assert_eq!(sponsors.get(1).unwrap().token, usdc);
+
+    set_time(&t, 150);
+    let alice = submit(&t, &id, "alice");
+    set_time(&t, 350);
+    vote(&t, &id, "alice", 1);
+
+    set_time(&t, 500);
+    t.client.pay_winners(&id);
+    assert_eq!(t.client.get_pending_payouts(&id).len(), 2);
+    t.client.claim_prize(&id, &text(&t.env, "alice"));
+    assert_eq!(balance(&t, &alice), 1000);
+    assert_eq!(TokenClient::new(&t.env, &usdc).balance(&alice), 300);
+}
+
+#[test]
+fn test_cancel_refunds_funders_and_entry_fees() {
+    let t = setup();
+    t.client.set_platform_fee(&t.admin, &1000, &FeeMode::OnFund);
+    let id = create(&t, "cancel");
+    t.client.set_entry_fee(&id, &t.admin, &None, &100, &50);
+    let funder = fund(&t, &id, 1000);
+
+    set_time(&t, 150);
+    let artist = Address::generate(&t.env);
+    mint(&t, &artist, 100);
+    let env = &t.env;
+    t.client.submit_art(
+        &id,
+        &artist,
+        &text(env, "alice"),
+        &text(env, "art"),
+        &text(env, "desc"),
+        &text(env, "url"),
+    );
+
+    t.client.cancel_competition(&id, &t.admin);
+    assert_eq!(balance(&t, &artist), 100);
+    t.client.claim_refund(&id, &funder);
+    assert_eq!(balance(&t, &funder), 1000);
+    assert!(t.client.try_claim_refund(&id, &funder).is_err());
+
+    t.client.archive_competition(&id, &t.admin);
+    assert_eq!(balance(&t, &t.contract), 0);
+}
+
+#[test]
+fn test_vesting_withdraw_and_reclaim() {
+    let t = setup();
+    t.client.set_claim_window(&t.admin, &50);
+    let id = create(&t, "vesting");
+    t.client.set_payout_schedule(&id, &t.admin, &0, &100);
+    fund(&t, &id, 1000);
+
+    set_time(&t, 150);
+    let alice = submit(&t, &id, "alice");
+    set_time(&t, 350);
+    vote(&t, &id, "alice", 1);
+
+    set_time(&t, 500);
+    t.client.pay_winners(&id);
+    set_time(&t, 550);
+    t.client.withdraw_vested(&id, &text(&t.env, "alice"));
+    assert_eq!(balance(&t, &alice), 500);
+
+    // Deadline is the vesting duration plus the claim window
+    set_time(&t, 651);
+    assert!(t.client.try_withdraw_vested(&id, &text(&t.env, "alice")).is_err());
+    t.client.reclaim_expired_prizes(&id, &t.admin);
+    assert_eq!(balance(&t, &t.admin), 500);
+    t.client.archive_competition(&id, &t.admin);
+    assert_eq!(balance(&t, &t.contract), 0);
+}
+
+fn tied(t: &Setup, id: &str, tie_break: TieBreak) -> String {
+    let id = create(t, id);
+    t.client.set_tie_break(&id, &t.admin, &tie_break);
+    set_time(t, 150);
+    submit(t, &id, "alice");
+    submit(t, &id, "bob");
+    set_time(t, 350);
+    vote(t, &id, "alice", 1);
+    vote(t, &id, "bob", 1);
+    set_time(t, 500);
+    id
+}
+
+fn ranks(t: &Setup, id: &String) -> Vec<(String, u32)> {
+    let mut ranks = Vec::new(&t.env);
+    for entry in t.client.get_competition(id).ranking.iter() {
+        ranks.push_back((entry.artist, entry.rank));
+    }
+    ranks
+}
+
+#[test]
+fn test_tie_breaks() {
+    let t = setup();
+    let alice = text(&t.env, "alice");
+    let bob = text(&t.env, "bob");
+    let keeper = Address::generate(&t.env);
+
+    let id = tied(&t, "split", TieBreak::SplitEvenly);
+    t.client.finalize(&id, &keeper);
+    assert_eq!(ranks(&t, &id), vec![&t.env, (alice.clone(), 1), (bob.clone(), 1)]);
+
+    let id = tied(&t, "earliest", TieBreak::EarliestSubmission);
+    t.client.finalize(&id, &keeper);
+    assert_eq!(ranks(&t, &id), vec![&t.env, (alice.clone(), 1), (bob.clone(), 2)]);
+
+    let id = tied(&t, "jury", TieBreak::JuryDecides);
+    assert!(t.client.try_finalize(&id, &keeper).is_err());
+    t.client
+        .set_jury_order(&id, &t.admin, &vec![&t.env, bob.clone(), alice.clone()]);
+    t.client.finalize(&id, &keeper);
+    assert_eq!(ranks(&t, &id), vec![&t.env, (bob.clone(), 1), (alice.clone(), 2)]);
+
+    let id = tied(&t, "random", TieBreak::Random);
+    t.client.finalize(&id, &keeper);
+    let ranking = t.client.get_winner(&id);
+    assert_eq!(ranking.get(0).unwrap().rank, 1);
+    assert_eq!(ranking.get(1).unwrap().rank, 2);
+}
+
+#[test]
+fn test_payout_records_track_status() {
+    let t = setup();
+    let id = create(&t, "records");
+    fund(&t, &id, 1000);
+
+    set_time(&t, 150);
+    submit(&t, &id, "alice");
+    submit(&t, &id, "bob");
+    set_time(&t, 350);
+    vote(&t, &id, "alice", 2);
+    vote(&t, &id, "bob", 1);
+
+    set_time(&t, 500);
+    t.client.pay_winners(&id);
+    // The unused third place share is recorded as leftover on top of each main share
+    let records = t.client.get_payouts(&id);
+    assert_eq!(records.len(), 4);
+    let first = records.get(0).unwrap();
+    assert_eq!(first.artist, text(&t.env, "alice"));
+    assert_eq!(first.rank, 1);
+    assert!(first.kind == PayoutKind::Main);
+    assert!(records.iter().all(|record| record.status == PayoutStatus::Pending));
+    assert_eq!(
+        records.iter().map(|record| record.amount).sum::<i128>(),
+        1000
+    );
+
+    t.client.claim_prize(&id, &text(&t.env, "alice"));
+    for record in t.client.get_payouts(&id).iter() {
+        let expected = if record.artist == text(&t.env, "alice") {
+            PayoutStatus::Paid
+        } else {
+            PayoutStatus::Pending
+        };
+        assert!(record.status == expected);
+    }
+}
+
+#[test]
+fn test_rollover_settles_competition() {
+    let t = setup();
+    let next = create(&t, "next");
+    let id = create(&t, "first");
+    t.client.set_successor(&id, &t.admin, &Some(next.clone()));
+    fund(&t, &id, 1000);
+
+    set_time(&t, 500);
+    t.client.pay_winners(&id);
+    assert_eq!(t.client.get_pot(&id), 0);
+    assert_eq!(t.client.get_pot(&next), 1000);
+    assert!(t.client.get_competition(&id).state == CompetitionState::Paid);
+    assert!(t.client.try_cancel_competition(&id, &t.admin).is_err());
+    t.client.archive_competition(&id, &t.admin);
+}
+
+#[test]
+fn test_rounding_remainder_rolls_over() {
+    let t = setup();
+    let next = create(&t, "next");
+    let id = create(&t, "dust");
+    t.client.set_successor(&id, &t.admin, &Some(next.clone()));
+    fund(&t, &id, 1001);
+
+    set_time(&t, 150);
+    let alice = submit(&t, &id, "alice");
+    submit(&t, &id, "bob");
+    submit(&t, &id, "carol");
+    set_time(&t, 350);
+    vote(&t, &id, "alice", 3);
+    vote(&t, &id, "bob", 2);
+    vote(&t, &id, "carol", 1);
+
+    set_time(&t, 500);
+    t.client.pay_winners(&id);
+    t.client.claim_prize(&id, &text(&t.env, "alice"));
+    assert_eq!(balance(&t, &alice), 500);
+    assert_eq!(t.client.get_pot(&next), 1);
+    assert_eq!(t.client.get_pot(&id), 500);
+}
+
+#[test]
+fn test_update_competition_schedule() {
+    let t = setup();
+    let id = create(&t, "update");
+    let env = &t.env;
+
+    t.client.update_competition(
+        &id,
+        &t.admin,
+        &Some(text(env, "Renamed")),
+        &None,
+        &Some(250),
+        &Some(300),
+        &None,
+        &Some(5),
+    );
+    let comp = t.client.get_competition(&id);
+    assert_eq!(comp.id_description, text(env, "Renamed"));
+    assert_eq!(comp.artist_add_end, 250);
+    assert_eq!(t.client.get_min_vote_tokens(&id), 5);
+    assert!(t
+        .client
+        .try_update_competition(&id, &t.admin, &None, &None, &None, &Some(200), &None, &None)
+        .is_err());
+
+    // Once a phase started it can only be extended
+    set_time(&t, 150);
+    assert!(t
+        .client
+        .try_update_competition(&id, &t.admin, &None, &Some(160), &None, &None, &None, &None)
+        .is_err());
+    assert!(t
+        .client
+        .try_update_competition(&id, &t.admin, &None, &None, &Some(240), &None, &None, &None)
+        .is_err());
+    t.client
+        .update_competition(&id, &t.admin, &None, &None, &Some(280), &None, &None, &None);
+    assert_eq!(t.client.get_competition(&id).artist_add_end, 280);
+
+    set_time(&t, 350);
+    assert!(t
+        .client
+        .try_update_competition(&id, &t.admin, &None, &None, &None, &None, &None, &Some(0))
+        .is_err());
+}
+
+#[test]
+fn test_paused_competition_is_extended_on_resume() {
+    let t = setup();
+    let id = create(&t, "paused");
+    set_time(&t, 150);
+    submit(&t, &id, "alice");
+
+    set_time(&t, 350);
+    t.client.pause_competition(&id, &t.admin);
+    assert!(t
+        .client
+        .try_vote(&id, &Address::generate(&t.env), &text(&t.env, "alice"))
+        .is_err());
+
+    set_time(&t, 450);
+    t.client.resume_competition(&id, &t.admin, &true);
+    assert_eq!(t.client.get_competition(&id).vote_end, 500);
+    vote(&t, &id, "alice", 1);
+}
+
+#[test]
+fn test_global_pause_keeps_claim_window_and_admin_calls() {
+    let t = setup();
+    t.client.set_claim_window(&t.admin, &100);
+    let id = create(&t, "global");
+    fund(&t, &id, 1000);
+    set_time(&t, 150);
+    submit(&t, &id, "alice");
+    submit(&t, &id, "bob");
+    set_time(&t, 350);
+    vote(&t, &id, "alice", 2);
+    vote(&t, &id, "bob", 1);
+
+    set_time(&t, 420);
+    t.client.pause_all(&t.admin);
+    assert!(t.client.try_pay_winners(&id).is_err());
+    // Admin corrections still go through while paused
+    t.client
+        .disqualify(&id, &t.admin, &text(&t.env, "bob"), &text(&t.env, "plagiarism"));
+
+    t.client.resume_all(&t.admin, &false);
+    set_time(&t, 500);
+    t.client.pay_winners(&id);
+    assert_eq!(t.client.get_competition(&id).claim_deadline, 600);
+
+    t.client.pause_all(&t.admin);
+    set_time(&t, 580);
+    assert!(t.client.try_claim_prize(&id, &text(&t.env, "alice")).is_err());
+    set_time(&t, 650);
+    t.client.resume_all(&t.admin, &true);
+    assert_eq!(t.client.get_competition(&id).claim_deadline, 750);
+    t.client.claim_prize(&id, &text(&t.env, "alice"));
+}
+
+#[test]
+fn test_draft_competition_opens_once_published() {
+    let t = setup();
+    let id = text(&t.env, "draft");
+    set_time(&t, 10);
+    t.client.create_draft_competition(
+        &t.admin,
+        &id,
+        &text(&t.env, "Test competition"),
+        &100,
+        &200,
+        &300,
+        &400,
+        &t.token,
+        &0,
+    );
+    set_time(&t, 150);
+    let artist = Address::generate(&t.env);
+    let name = text(&t.env, "alice");
+    assert!(t
+        .client
+        .try_submit_art(&id, &artist, &name, &name, &name, &name)
+        .is_err());
+
+    t.client.publish_competition(&id, &t.admin);
+    assert!(t.client.get_competition(&id).state == CompetitionState::Submissions);
+    assert!(t.client.try_publish_competition(&id, &t.admin).is_err());
+    submit(&t, &id, "alice");
+}
+
+#[test]
+fn test_migrate_storage_from_v1() {
+    let t = setup();
+    let env = &t.env;
+    let voter = Address::generate(env);
+    let artist = Address::generate(env);
+    let running = text(env, "running");
+    let done = text(env, "done");
+    set_time(&t, 150);
+
+    // Write competitions the way version 1.0.1 stored them
+    let legacy = |id: &String, finalized: bool, pot: u64| CompetitionV1 {
+        id: id.clone(),
+        id_description: text(env, "Legacy"),
+        token: t.token.clone(),
+        artist_add_start: 100,
+        artist_add_end: 200,
+        vote_start: 300,
+        vote_end: 400,
+        min_vote_tokens: 2,
+        artists: vec![env, (artist.clone(), text(env, "alice"))],
+        votes: Map::from_array(env, [(text(env, "alice"), 1u64)]),
+        vote_log: Map::from_array(env, [(voter.clone(), text(env, "alice"))]),
+        finalized,
+        winner: if finalized { Some(text(env, "alice")) } else { None },
+        pot,
+        artist_metadata: Map::new(env),
+        share_ratio: vec![env, 50u32, 30, 20],
+    };
+    env.as_contract(&t.contract, || {
+        let storage = env.storage().instance();
+        storage.set(&running, &legacy(&running, false, 5));
+        storage.set(&done, &legacy(&done, true, 0));
+        storage.set(&Symbol::new(env, "comp_list"), &vec![env, running.clone(), done.clone()]);
+        let history = vec![
+            env,
+            VoteHistoryV1 {
+                voter: voter.clone(),
+                artist: text(env, "alice"),
+                timestamp: 350,
+            },
+        ];
+        storage.set(
+            &Symbol::new(env, "vote_hist"),
+            &Map::from_array(env, [(done.clone(), history)]),
+        );
+        storage.remove(&Symbol::new(env, "storage_ver"));
+    });
+    let blocked = t.client.try_create_competition(
+        &t.admin,
+        &text(env, "new"),
+        &text(env, "New"),
+        &300,
+        &400,
+        &500,
+        &600,
+        &t.token,
+        &0,
+    );
+    assert!(blocked.is_err());
+
+    t.client.migrate_storage(&t.admin);
+    assert!(t.client.try_migrate_storage(&t.admin).is_err());
+
+    // The test token has 7 decimals
+    let comp = t.client.get_competition(&running);
+    assert!(comp.state == CompetitionState::Submissions);
+    assert_eq!(t.client.get_pot(&running), 50_000_000);
+    assert_eq!(t.client.get_min_vote_tokens(&running), 20_000_000);
+
+    let comp = t.client.get_competition(&done);
+    assert!(comp.state == CompetitionState::Paid);
+    assert_eq!(comp.ranking.get(0).unwrap().artist, text(env, "alice"));
+    let history = t.client.get_vote_history(&done);
+    assert_eq!(history.get(0).unwrap().voter, voter);
+    assert_eq!(history.get(0).unwrap().artwork, None);
+
+    assert_eq!(t.client.version(), text(env, "2.0.0"));
+    create(&t, "new");
+}
+
+#[test]
+fn test_no_winner_competition_can_be_cancelled() {
+    let t = setup();
+    t.client.set_platform_fee(&t.admin, &1000, &FeeMode::OnFund);
+    let id = create(&t, "nowinner");
+    let funder = fund(&t, &id, 1000);
+
+    // Without votes or a successor the pot and held fee stay untouched
+    set_time(&t, 500);
+    t.client.pay_winners(&id);
+    assert!(t.client.get_competition(&id).state == CompetitionState::Finalized);
+    t.client.cancel_competition(&id, &t.admin);
+    t.client.claim_refund(&id, &funder);
+    assert_eq!(balance(&t, &funder), 1000);
+    t.client.archive_competition(&id, &t.admin);
+}
+
+#[test]
+fn test_keeper_reward_blocks_cancel() {
+    let t = setup();
+    t.client.set_keeper_reward(&t.admin, &10);
+    let id = create(&t, "keeper");
+    fund(&t, &id, 1000);
+
+    set_time(&t, 150);
+    let alice = submit(&t, &id, "alice");
+    set_time(&t, 350);
+    vote(&t, &id, "alice", 1);
+
+    set_time(&t, 500);
+    let keeper = Address::generate(&t.env);
+    t.client.finalize(&id, &keeper);
+    assert_eq!(balance(&t, &keeper), 10);
+    assert!(t.client.try_cancel_competition(&id, &t.admin).is_err());
+
+    t.client.pay_winners(&id);
+    t.client.claim_prize(&id, &text(&t.env, "alice"));
+    assert_eq!(balance(&t, &alice), 990);
+    t.client.archive_competition(&id, &t.admin);
+    assert_eq!(balance(&t, &t.contract), 0);
+}
+
+#[test]
+fn test_keeper_reward_blocks_cancel_without_winner() {
+    let t = setup();
+    t.client.set_keeper_reward(&t.admin, &10);
+    let id = create(&t, "keeper");
+    fund(&t, &id, 1000);
+
+    set_time(&t, 500);
+    t.client.finalize(&id, &Address::generate(&t.env));
+    let comp = t.client.get_competition(&id);
+    assert_eq!(comp.winner, None);
+    assert_eq!(comp.keeper_paid, 10);
+    assert!(t.client.try_cancel_competition(&id, &t.admin).is_err());
+}
+
+#[test]
+fn test_series_editions() {
+    let t = setup();
+    let env = &t.env;
+    let series = text(env, "weekly");
+    let schedule = SeriesSchedule {
+        submission_duration: 100,
+        voting_gap: 50,
+        voting_duration: 100,
+        cadence: 1000,
+    };
+    set_time(&t, 10);
+    t.client.create_series(
+        &t.admin,
+        &series,
+        &text(env, "Weekly"),
+        &t.token,
+        &0,
+        &vec![env, 70u32, 30],
+        &schedule,
+        &100,
+    );
+
+    let first = t.client.start_next_edition(&series);
+    assert_eq!(first, text(env, "weekly-001"));
+    let comp = t.client.get_competition(&first);
+    assert!(comp.state == CompetitionState::Scheduled);
+    assert_eq!((comp.artist_add_start, comp.vote_end), (100, 350));
+    assert_eq!(comp.share_ratio, vec![env, 70u32, 30]);
+    assert!(t.client.try_start_next_edition(&series).is_err());
+
+    // An id taken by hand is skipped and a late edition starts right away
+    create(&t, "weekly-002");
+    set_time(&t, 1500);
+    let second = t.client.start_next_edition(&series);
+    assert_eq!(second, text(env, "weekly-003"));
+    assert_eq!(t.client.get_competition(&second).artist_add_start, 1500);
+    assert_eq!(t.client.get_series_editions(&series).len(), 2);
+
+    t.client.set_series_active(&t.admin, &series, &false);
+    set_time(&t, 2000);
+    assert!(t.client.try_start_next_edition(&series).is_err());
+}
+
+#[test]
+fn test_waitlist_fees_refunded() {
+    let t = setup();
+    let id = create(&t, "waitlist");
+    t.client.set_entry_fee(&id, &t.admin, &None, &100, &100);
+    t.client.set_submission_limits(&id, &t.admin, &1, &0);
+
+    set_time(&t, 150);
+    let env = &t.env;
+    let mut artists = [
+        Address::generate(env),
+        Address::generate(env),
+        Address::generate(env),
+    ];
+    for (i, name) in ["alice", "bob", "carol"].iter().enumerate() {
+        mint(&t, &artists[i], 100);
+        t.client.submit_art(
+            &id,
+            &artists[i],
+            &text(env, name),
+            &text(env, name),
+            &text(env, name),
+            &text(env, name),
+        );
+    }
+    assert_eq!(t.client.get_waitlist(&id).len(), 2);
+    assert_eq!(t.client.get_pot(&id), 100);
+
+    t.client.leave_waitlist(&id, &artists[1]);
+    assert_eq!(balance(&t, &artists[1]), 100);
+
+    t.client.cancel_competition(&id, &t.admin);
+    artists.swap(1, 2);
+    for artist in artists.iter() {
+        assert_eq!(balance(&t, artist), 100);
+    }
+    t.client.archive_competition(&id, &t.admin);
+    assert_eq!(balance(&t, &t.contract), 0);
+}
+
+#[test]
+fn test_artworks_added_edited_and_voted() {
+    let t = setup();
+    let env = &t.env;
+    let id = create(&t, "artworks");
+    let alice_name = text(env, "alice");
+
+    set_time(&t, 150);
+    let alice = submit(&t, &id, "alice");
+    let add = |name: &str| {
+        t.client.add_artwork(
+            &id,
+            &alice_name,
+            &alice,
+            &text(env, name),
+            &text(env, name),
+            &text(env, name),
+        )
+    };
+    add("second");
+    add("third");
+    // Another artwork's description is a duplicate
+    let duplicate = t.client.try_add_artwork(
+        &id,
+        &alice_name,
+        &alice,
+        &text(env, "fourth"),
+        &text(env, "second"),
+        &text(env, "fourth"),
+    );
+    assert!(duplicate.is_err());
+    t.client
+        .remove_artwork(&id, &alice_name, &alice, &text(env, "third"));
+
+    // The selected artwork is edited and may not take another artwork's name
+    t.client.update_submission_metadata(
+        &id,
+        &alice_name,
+        &alice,
+        &text(env, "second"),
+        &Some(text(env, "renamed")),
+        &None,
+    );
+    let taken = t.client.try_update_submission_metadata(
+        &id,
+        &alice_name,
+        &alice,
+        &text(env, "renamed"),
+        &Some(alice_name.clone()),
+        &None,
+    );
+    assert!(taken.is_err());
+    let artworks = t.client.get_comp_artists(&id);
+    assert_eq!(artworks.len(), 2);
+    assert_eq!(artworks.get(0).unwrap().1.artwork_name, alice_name);
+    assert_eq!(artworks.get(1).unwrap().1.artwork_name, text(env, "renamed"));
+
+    set_time(&t, 350);
+    let voter = Address::generate(env);
+    t.client
+        .vote_for_artwork(&id, &voter, &alice_name, &text(env, "renamed"));
+    assert!(t
+        .client
+        .try_vote_for_artwork(&id, &Address::generate(env), &alice_name, &text(env, "third"))
+        .is_err());
+    let votes = t.client.get_artwork_votes(&id, &alice_name);
+    assert_eq!(votes.get(text(env, "renamed")), Some(1));
+    assert_eq!(t.client.get_competition(&id).votes.get(alice_name), Some(1));
+}
+
+#[test]
+fn test_withdraw_submission_refunds_fee() {
+    let t = setup();
+    let env = &t.env;
+    let id = create(&t, "withdraw");
+    t.client.set_entry_fee(&id, &t.admin, &None, &100, &100);
+
+    set_time(&t, 150);
+    let artists = [
+        Address::generate(env),
+        Address::generate(env),
+        Address::generate(env),
+    ];
+    for (artist, name) in artists.iter().zip(["alice", "bob", "carol"]) {
+        mint(&t, artist, 100);
+        let name = text(env, name);
+        t.client.submit_art(&id, artist, &name, &name, &name, &name);
+    }
+    assert_eq!(t.client.get_pot(&id), 300);
+
+    t.client.withdraw_submission(&id, &artists[0]);
+    assert_eq!(balance(&t, &artists[0]), 100);
+    assert_eq!(t.client.get_pot(&id), 200);
+    assert_eq!(t.client.get_comp_artists(&id).len(), 2);
+
+    // Withdrawing during voting takes the artist's votes with them
+    t.client.set_withdraw_during_voting(&id, &t.admin, &true);
+    set_time(&t, 350);
+    vote(&t, &id, "bob", 1);
+    t.client.withdraw_submission(&id, &artists[1]);
+    assert_eq!(balance(&t, &artists[1]), 100);
+    assert_eq!(t.client.get_competition(&id).votes.len(), 0);
+    assert_eq!(t.client.get_vote_history(&id).len(), 0);
+
+    set_time(&t, 450);
+    assert!(t.client.try_withdraw_submission(&id, &artists[2]).is_err());
+}
+
+fn register(t: &Setup, artist: &Address, name: &str) {
+    let env = &t.env;
+    t.client.add_artist_info(
+        artist,
+        &text(env, name),
+        &text(env, "bio"),
+        &text(env, "img"),
+        &text(env, "site"),
+        &Vec::new(env),
+        &Vec::new(env),
+    );
+}
+
+fn stats(t: &Setup, artist: &Address) -> (u32, u32) {
+    let info = t.client.get_artist_info(artist).unwrap();
+    (info.competitions_participated, info.competitions_won)
+}
+
+#[test]
+fn test_profile_counters_only_roll_back_what_was_counted() {
+    let t = setup();
+    let env = &t.env;
+    let first = create(&t, "first");
+    let second = create(&t, "second");
+
+    // Alice enters the first competition before she has a profile
+    set_time(&t, 150);
+    let alice = submit(&t, &first, "alice");
+    register(&t, &alice, "alice");
+    let name = text(env, "alice");
+    t.client.submit_art(&second, &alice, &name, &name, &name, &name);
+    assert_eq!(stats(&t, &alice), (1, 0));
+    t.client.withdraw_submission(&first, &alice);
+    assert_eq!(stats(&t, &alice), (1, 0));
+    t.client.withdraw_submission(&second, &alice);
+    assert_eq!(stats(&t, &alice), (0, 0));
+}
+
+#[test]
+fn test_disqualified_winner_loses_the_credited_win() {
+    let t = setup();
+    let id = create(&t, "wins");
+    set_time(&t, 150);
+    let alice = submit(&t, &id, "alice");
+    let bob = submit(&t, &id, "bob");
+    register(&t, &alice, "alice");
+    set_time(&t, 350);
+    vote(&t, &id, "alice", 2);
+    vote(&t, &id, "bob", 1);
+
+    // Alice registered after entering, only her win is counted
+    set_time(&t, 500);
+    t.client.finalize(&id, &Address::generate(&t.env));
+    assert_eq!(stats(&t, &alice), (0, 1));
+
+    register(&t, &bob, "bob");
+    t.client
+        .disqualify(&id, &t.admin, &text(&t.env, "alice"), &text(&t.env, "plagiarism"));
+    assert_eq!(stats(&t, &alice), (0, 0));
+    assert_eq!(stats(&t, &bob), (0, 1));
+    assert_eq!(t.client.get_competition(&id).winner, Some(text(&t.env, "bob")));
+}
+
+#[test]
+fn test_moderated_submissions_need_approval() {
+    let t = setup();
+    let env = &t.env;
+    let id = create(&t, "moderated");
+    t.client.set_moderation(&id, &t.admin, &true);
+    let alice_name = text(env, "alice");
+    let bob_name = text(env, "bob");
+
+    set_time(&t, 150);
+    let alice = submit(&t, &id, "alice");
+    let bob = submit(&t, &id, "bob");
+    assert_eq!(t.client.get_pending_submissions(&id).len(), 2);
+    assert_eq!(t.client.get_comp_artists(&id).len(), 0);
+
+    t.client
+        .review_submission(&id, &t.admin, &alice_name, &true, &None);
+    t.client
+        .review_submission(&id, &t.admin, &bob_name, &false, &Some(3));
+    assert_eq!(t.client.get_comp_artists(&id).len(), 1);
+    assert!(t
+        .client
+        .try_review_submission(&id, &t.admin, &bob_name, &true, &None)
+        .is_err());
+
+    // A resubmission or an edit goes back to review
+    let new = text(env, "new");
+    t.client
+        .resubmit_art(&id, &bob_name, &bob, &new, &new, &new);
+    t.client.update_submission_metadata(
+        &id,
+        &alice_name,
+        &alice,
+        &alice_name,
+        &None,
+        &Some(text(env, "edited")),
+    );
+    assert_eq!(t.client.get_pending_submissions(&id).len(), 2);
+    t.client
+        .review_submission(&id, &t.admin, &alice_name, &true, &None);
+
+    // Approved entries can't be edited back into review once submissions close
+    set_time(&t, 350);
+    let late = t.client.try_update_submission_metadata(
+        &id,
+        &alice_name,
+        &alice,
+        &alice_name,
+        &None,
+        &Some(text(env, "late")),
+    );
+    assert!(late.is_err());
+    assert!(t
+        .client
+        .try_vote(&id, &Address::generate(env), &bob_name)
+        .is_err());
+    vote(&t, &id, "alice", 1);
+}
+
+#[test]
+fn test_reported_leader_ranked_until_upheld() {
+    let t = setup();
+    let id = create(&t, "reports");
+    t.client.set_report_policy(&id, &t.admin, &0, &10, &1);
+
+    set_time(&t, 150);
+    submit(&t, &id, "alice");
+    submit(&t, &id, "bob");
+    set_time(&t, 350);
+    vote(&t, &id, "alice", 2);
+    vote(&t, &id, "bob", 1);
+
+    let reporter = Address::generate(&t.env);
+    mint(&t, &reporter, 10);
+    t.client
+        .report(&id, &text(&t.env, "alice"), &ReportReason::Plagiarism, &reporter);
+    assert!(t
+        .client
+        .try_vote(&id, &Address::generate(&t.env), &text(&t.env, "alice"))
+        .is_err());
+
+    // Hidden from voting but still ranked until a moderator upholds the reports
+    set_time(&t, 500);
+    t.client.finalize(&id, &t.admin);
+    assert_eq!(t.client.get_competition(&id).winner, Some(text(&t.env, "alice")));
+
+    // Open deposits block archiving until the reports are resolved
+    assert!(t.client.try_archive_competition(&id, &t.admin).is_err());
+    t.client
+        .resolve_reports(&id, &t.admin, &text(&t.env, "alice"), &true);
+    assert_eq!(balance(&t, &reporter), 10);
+    assert_eq!(t.client.get_competition(&id).winner, Some(text(&t.env, "bob")));
+    t.client.archive_competition(&id, &t.admin);
+}
+
+#[test]
+fn test_dismissed_report_forfeits_deposit() {
+    let t = setup();
+    let id = create(&t, "dismissed");
+    t.client.set_report_policy(&id, &t.admin, &1, &10, &0);
+
+    set_time(&t, 150);
+    submit(&t, &id, "alice");
+    submit(&t, &id, "bob");
+    let reporter = Address::generate(&t.env);
+    mint(&t, &reporter, 20);
+    t.client
+        .report(&id, &text(&t.env, "alice"), &ReportReason::Nsfw, &reporter);
+    assert!(t
+        .client
+        .try_report(&id, &text(&t.env, "bob"), &ReportReason::Nsfw, &reporter)
+        .is_err());
+
+    t.client
+        .resolve_reports(&id, &t.admin, &text(&t.env, "alice"), &false);
+    assert_eq!(balance(&t, &reporter), 10);
+    assert_eq!(t.client.get_treasury_balance(&t.token), 10);
+    assert_eq!(t.client.get_report_counts(&id).len(), 0);
+}
+
+#[test]
+fn test_failed_report_refund_is_claimable() {
+    let t = setup();
+    let id = create(&t, "deposits");
+    t.client.set_report_policy(&id, &t.admin, &0, &10, &0);
+
+    set_time(&t, 150);
+    submit(&t, &id, "alice");
+    let reporter = Address::generate(&t.env);
+    mint(&t, &reporter, 10);
+    t.client
+        .report(&id, &text(&t.env, "alice"), &ReportReason::RuleViolation, &reporter);
+
+    set_authorized(&t, &reporter, false);
+    t.client.cancel_competition(&id, &t.admin);
+    assert!(t.client.try_archive_competition(&id, &t.admin).is_err());
+
+    set_authorized(&t, &reporter, true);
+    t.client.claim_report_refund(&id, &reporter);
+    assert_eq!(balance(&t, &reporter), 10);
+    assert!(t.client.try_claim_report_refund(&id, &reporter).is_err());
+    t.client.archive_competition(&id, &t.admin);
+    assert_eq!(balance(&t, &t.contract), 0);
+}
+
+#[test]
+fn test_disqualified_artist_is_skipped_in_payouts() {
+    let t = setup();
+    let env = &t.env;
+    let id = create(&t, "disqualify");
+    fund(&t, &id, 1000);
+    let alice_name = text(env, "alice");
+    let reason = text(env, "plagiarism");
+
+    set_time(&t, 150);
+    submit(&t, &id, "alice");
+    let bob = submit(&t, &id, "bob");
+    set_time(&t, 350);
+    vote(&t, &id, "alice", 2);
+    vote(&t, &id, "bob", 1);
+    assert!(t
+        .client
+        .try_disqualify(&id, &t.admin, &alice_name, &reason)
+        .is_err());
+
+    // Disqualified before finalizing, the artist is never ranked but keeps their votes on record
+    set_time(&t, 500);
+    t.client.disqualify(&id, &t.admin, &alice_name, &reason);
+    assert!(t
+        .client
+        .try_disqualify(&id, &t.admin, &alice_name, &reason)
+        .is_err());
+    t.client.pay_winners(&id);
+    let comp = t.client.get_competition(&id);
+    assert_eq!(comp.winner, Some(text(env, "bob")));
+    assert_eq!(comp.ranking.len(), 1);
+    assert_eq!(comp.votes.get(alice_name.clone()), Some(2));
+    assert_eq!(comp.disqualified.get(alice_name.clone()), Some(reason.clone()));
+
+    t.client.claim_prize(&id, &text(env, "bob"));
+    assert_eq!(balance(&t, &bob), 1000);
+    assert!(t
+        .client
+        .try_disqualify(&id, &t.admin, &text(env, "bob"), &reason)
+        .is_err());
+}